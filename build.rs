@@ -0,0 +1,262 @@
+// Convert the postcard-serialized tables in `src/data` into Rust source, so that the library can
+// embed them as static arrays instead of deserializing them at runtime. Each output file contains a
+// single expression, which `consts.rs` pulls in with `include!`.
+
+use serde::Deserialize;
+use std::fmt::{Display, Write};
+use std::path::Path;
+use std::{env, fs};
+
+#[derive(Deserialize)]
+struct CollationTable {
+    page_index: Vec<u16>,
+    entries: Vec<u64>,
+    contraction_meta: Vec<ContractionMeta>,
+    edges: Vec<ContractionEdge>,
+    weights: Vec<u32>,
+    prefixes: Vec<PrefixMapping>,
+}
+
+#[derive(Deserialize)]
+struct ContractionMeta {
+    first_edge: u32,
+    edge_len: u16,
+    max_len: u8,
+}
+
+#[derive(Deserialize)]
+struct ContractionEdge {
+    code_point: u32,
+    next_first_edge: u32,
+    weight_start: u32,
+    next_edge_len: u16,
+    weight_len: u16,
+}
+
+#[derive(Deserialize)]
+struct PrefixMapping {
+    code_point: u32,
+    prefix: Vec<u32>,
+    weight_start: u32,
+    weight_len: u16,
+}
+
+#[derive(Deserialize)]
+struct HanTable {
+    lead: u16,
+    page_index: Vec<u16>,
+    pages: Vec<u16>,
+}
+
+#[derive(Deserialize)]
+struct VariableTable {
+    page_index: Vec<u16>,
+    pages: Vec<u64>,
+}
+
+#[derive(Deserialize)]
+struct FcdTable {
+    page_index: Vec<u16>,
+    pages: Vec<u16>,
+}
+
+#[derive(Deserialize)]
+struct DecompTable {
+    page_index: Vec<u16>,
+    entries: Vec<u64>,
+    values: Vec<u32>,
+}
+
+// Each table, with the features that use it (any one of them is enough; the tables with none are
+// always used). Tables whose features are all off are not converted.
+const COLLATION_TABLES: [(&str, &[&str]); 45] = [
+    ("ducet", &[]),
+    ("cldr_root", &[]),
+    ("tailoring/arabic_script", &[]),
+    ("tailoring/arabic_interleaved", &[]),
+    ("tailoring/german_phonebook", &["german-phonebook"]),
+    ("tailoring/swedish", &["swedish"]),
+    ("tailoring/finnish", &["finnish"]),
+    ("tailoring/danish", &["danish"]),
+    ("tailoring/norwegian", &["norwegian"]),
+    ("tailoring/spanish", &["spanish"]),
+    ("tailoring/spanish_traditional", &["spanish"]),
+    ("tailoring/turkish", &["turkish"]),
+    ("tailoring/azerbaijani", &["azerbaijani"]),
+    ("tailoring/polish", &["polish"]),
+    ("tailoring/czech", &["czech"]),
+    ("tailoring/slovak", &["slovak"]),
+    ("tailoring/persian", &["persian"]),
+    ("tailoring/urdu", &["urdu"]),
+    ("tailoring/pashto", &["pashto"]),
+    ("tailoring/lithuanian", &["lithuanian"]),
+    ("tailoring/vietnamese", &["vietnamese"]),
+    ("tailoring/chinese_pinyin", &["chinese"]),
+    ("tailoring/chinese_stroke", &["chinese"]),
+    ("tailoring/chinese_zhuyin", &["chinese"]),
+    ("tailoring/japanese", &["japanese"]),
+    ("tailoring/korean", &["korean"]),
+    ("tailoring/hindi", &["hindi"]),
+    ("tailoring/bengali", &["bengali"]),
+    ("tailoring/tamil", &["tamil"]),
+    ("tailoring/hungarian", &["hungarian"]),
+    ("tailoring/emoji", &["emoji"]),
+    ("tailoring/greek", &["greek"]),
+    ("tailoring/cyrillic_script", &["russian", "bulgarian"]),
+    ("tailoring/ukrainian", &["ukrainian"]),
+    ("tailoring/serbian", &["serbian"]),
+    ("tailoring/hebrew", &["hebrew"]),
+    ("tailoring/thai", &["thai"]),
+    ("tailoring/lao", &["lao"]),
+    ("legacy/unicode_14/ducet", &["unicode-14"]),
+    ("legacy/unicode_14/cldr_root", &["unicode-14"]),
+    ("legacy/unicode_15/ducet", &["unicode-15", "cldr-43"]),
+    ("legacy/unicode_15/cldr_root", &["unicode-15"]),
+    ("legacy/cldr_43/cldr_root", &["cldr-43"]),
+    ("legacy/cldr_44/ducet", &["cldr-44"]),
+    ("legacy/cldr_44/cldr_root", &["cldr-44"]),
+];
+
+const HAN_TABLES: [(&str, &[&str]); 4] = [
+    ("han/pinyin", &["chinese"]),
+    ("han/stroke", &["chinese"]),
+    ("han/zhuyin", &["chinese"]),
+    ("han/japanese", &["japanese"]),
+];
+
+// Whether any of the features is enabled (or there are none)
+fn enabled(features: &[&str]) -> bool {
+    features.is_empty()
+        || features.iter().any(|feature| {
+            let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+            env::var_os(var).is_some()
+        })
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed=src/data");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+
+    for (name, features) in COLLATION_TABLES {
+        if !enabled(features) {
+            continue;
+        }
+
+        let table: CollationTable = read(name);
+
+        let mut meta = String::from("&[");
+        for m in &table.contraction_meta {
+            write!(
+                meta,
+                "ContractionMeta{{first_edge:{},edge_len:{},max_len:{}}},",
+                m.first_edge, m.edge_len, m.max_len
+            )
+            .unwrap();
+        }
+        meta.push(']');
+
+        let mut edges = String::from("&[");
+        for e in &table.edges {
+            write!(
+                edges,
+                "ContractionEdge{{code_point:{},next_first_edge:{},weight_start:{},\
+                 next_edge_len:{},weight_len:{}}},",
+                e.code_point, e.next_first_edge, e.weight_start, e.next_edge_len, e.weight_len
+            )
+            .unwrap();
+        }
+        edges.push(']');
+
+        let mut prefixes = String::from("&[");
+        for p in &table.prefixes {
+            write!(
+                prefixes,
+                "PrefixMapping{{code_point:{},prefix:{},weight_start:{},weight_len:{}}},",
+                p.code_point,
+                array(&p.prefix),
+                p.weight_start,
+                p.weight_len
+            )
+            .unwrap();
+        }
+        prefixes.push(']');
+
+        let code = format!(
+            "CollationTable{{page_index:{},entries:{},contraction_meta:{meta},edges:{edges},\
+             weights:{},prefixes:{prefixes},longest_contraction:{},tailors_ascii:false,base:None,\
+             han:None}}",
+            array(&table.page_index),
+            array(&table.entries),
+            array(&table.weights),
+            table
+                .contraction_meta
+                .iter()
+                .map(|m| m.max_len)
+                .max()
+                .unwrap_or(1),
+        );
+        write_out(out_dir, name, &code);
+    }
+
+    for (name, features) in HAN_TABLES {
+        if !enabled(features) {
+            continue;
+        }
+
+        let han: HanTable = read(name);
+        let code = format!(
+            "HanTable{{lead:{},page_index:{},pages:{}}}",
+            han.lead,
+            array(&han.page_index),
+            array(&han.pages)
+        );
+        write_out(out_dir, name, &code);
+    }
+
+    let variable: VariableTable = read("variable");
+    let code = format!(
+        "VariableTable{{page_index:{},pages:{}}}",
+        array(&variable.page_index),
+        array(&variable.pages)
+    );
+    write_out(out_dir, "variable", &code);
+
+    let fcd: FcdTable = read("fcd");
+    let code = format!(
+        "FcdTable{{page_index:{},pages:{}}}",
+        array(&fcd.page_index),
+        array(&fcd.pages)
+    );
+    write_out(out_dir, "fcd", &code);
+
+    let decomp: DecompTable = read("decomp");
+    let code = format!(
+        "DecompTable{{page_index:{},entries:{},values:{}}}",
+        array(&decomp.page_index),
+        array(&decomp.entries),
+        array(&decomp.values)
+    );
+    write_out(out_dir, "decomp", &code);
+}
+
+fn read<T: for<'de> Deserialize<'de>>(name: &str) -> T {
+    let bytes = fs::read(Path::new("src/data").join(name)).unwrap();
+    postcard::from_bytes(&bytes).unwrap()
+}
+
+fn array<T: Display>(values: &[T]) -> String {
+    let mut s = String::with_capacity(values.len() * 8 + 3);
+    s.push_str("&[");
+    for v in values {
+        write!(s, "{v},").unwrap();
+    }
+    s.push(']');
+    s
+}
+
+fn write_out(out_dir: &Path, name: &str, code: &str) {
+    let path = out_dir.join(format!("{}.rs", name.replace('/', "_")));
+    fs::write(path, code).unwrap();
+}
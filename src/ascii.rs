@@ -1,5 +1,7 @@
+use crate::tables::LOW_DEFER;
 use crate::weights::primary;
-use std::cmp::Ordering;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 
 pub enum AsciiResult {
     Continue {
@@ -44,14 +46,15 @@ impl<'a> PrimaryIter<'a> {
                 return Some(0);
             };
 
-            if !safe_ascii_low_byte(byte) {
+            if !byte.is_ascii() {
                 return None;
             }
 
             self.pos += 1;
 
+            // Give up on ignorables and on anything the table has to handle (e.g., contractions)
             let weights = self.low[usize::from(byte)];
-            if weights == 0 {
+            if weights == 0 || weights == LOW_DEFER {
                 return None;
             }
 
@@ -63,10 +66,6 @@ impl<'a> PrimaryIter<'a> {
     }
 }
 
-const fn safe_ascii_low_byte(byte: u8) -> bool {
-    byte < 0x80 && byte != b'L' && byte != b'l'
-}
-
 pub fn fill_codepoints_and_compare_ascii(
     a_iter: &mut impl Iterator<Item = u32>,
     b_iter: &mut impl Iterator<Item = u32>,
@@ -117,7 +116,21 @@ pub fn fill_codepoints_and_compare_ascii(
             continue;
         }
 
-        // We found a difference between ASCII characters; return it
+        // We found a difference between ASCII characters. It decides the comparison unless a
+        // non-ASCII character follows either of them, since a tailoring can give a letter and a
+        // combining mark a weight of their own (e.g., "a" + U+0308 as "ä" in Swedish).
+        let a_next = a_iter.next();
+        let b_next = b_iter.next();
+        a_chars.extend(a_next);
+        b_chars.extend(b_next);
+
+        if a_next.is_some_and(non_ascii) || b_next.is_some_and(non_ascii) {
+            a_needs_nfd |= a_next.is_some_and(|c| c >= 0xC0);
+            b_needs_nfd |= b_next.is_some_and(|c| c >= 0xC0);
+            ascii_failed = true;
+            break;
+        }
+
         return AsciiResult::Done(a_folded.cmp(&b_folded));
     }
 
@@ -138,8 +151,22 @@ pub fn fill_codepoints_and_compare_ascii(
     }
 
     // If we found no non-ASCII characters, and one string is a prefix of the other, the longer
-    // string wins.
+    // string wins. Again, that doesn't hold if it goes on with a non-ASCII character (e.g., "a" +
+    // U+0300 sorts before "a" in Chinese pinyin order).
     if a_chars.len() != b_chars.len() {
+        let (shorter, longer) = if a_chars.len() < b_chars.len() {
+            (&a_chars, &b_chars)
+        } else {
+            (&b_chars, &a_chars)
+        };
+
+        if non_ascii(longer[shorter.len()]) {
+            return AsciiResult::Continue {
+                a_needs_nfd,
+                b_needs_nfd,
+            };
+        }
+
         return AsciiResult::Done(a_chars.len().cmp(&b_chars.len()));
     }
 
@@ -153,6 +180,26 @@ pub fn fill_codepoints_and_compare_ascii(
     )
 }
 
+// For tailorings that reorder ASCII characters, just fill the code point Vecs
+pub fn fill_codepoints(
+    a_iter: &mut impl Iterator<Item = u32>,
+    b_iter: &mut impl Iterator<Item = u32>,
+    a_chars: &mut Vec<u32>,
+    b_chars: &mut Vec<u32>,
+) -> AsciiResult {
+    a_chars.extend(a_iter);
+    b_chars.extend(b_iter);
+
+    AsciiResult::Continue {
+        a_needs_nfd: a_chars.iter().any(|c| *c >= 0xC0),
+        b_needs_nfd: b_chars.iter().any(|c| *c >= 0xC0),
+    }
+}
+
+const fn non_ascii(c: u32) -> bool {
+    c >= 0x80
+}
+
 fn ascii_alphanumeric(c: u32) -> bool {
     (0x30..=0x7A).contains(&c)
         && !(0x3A..=0x40).contains(&c) // Punctuation and symbols
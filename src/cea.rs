@@ -1,15 +1,14 @@
-use crate::cea_match::{
-    implicit_a, implicit_b, try_discontiguous_contraction, try_pulled_contraction,
-};
+use crate::cea_match::{implicit_weights, try_discontiguous_contraction, try_pulled_contraction};
 use crate::cea_source::{CodePointSource, Utf8Source, VecSource};
 use crate::collator::CollationContext;
 #[cfg(feature = "pipeline-stats")]
 use crate::collator::PipelineStats;
 use crate::tables::CollationTable;
 use crate::weights::{primary, shift_weights, variability};
-use std::cmp::Ordering;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 
-const PENDING_CE_CAPACITY: usize = 20;
+pub const PENDING_CE_CAPACITY: usize = 20;
 
 pub enum LazyPrimaryResult {
     Decided(Ordering),
@@ -173,23 +172,36 @@ impl<'a, S: CodePointSource> CeaCursor<'a, S> {
 
         // Fast path for most low code points, including most ASCII characters that remain after
         // the initial ASCII check in `Collator::collate`.
-        if left_val < 0xB7 && left_val != 0x6C && left_val != 0x4C {
-            self.queue_weight(self.ctx.low[left_val as usize]);
+        if let Some(weights) = self.ctx.low_weights(left_val) {
+            self.queue_weight(weights);
             self.source.consume(1);
             return;
         }
 
         // Above the low table, the collation table gives us either a simple row, a missing entry
         // requiring implicit weights, or a contraction start with lookahead metadata.
-        let table = self.ctx.table;
-        let entry = table.entry(left_val);
+        let (table, entry) = self.ctx.table.entry(left_val);
+
+        // Some code points take different weights after certain others (e.g., the Japanese
+        // prolonged sound mark, which repeats the vowel of the kana before it).
+        if CollationTable::has_prefix(entry) {
+            let source = &mut self.source;
+            if let Some(row) = table.get_prefixed(left_val, |back| source.previous(back)) {
+                self.queue_row(row);
+                self.source.consume(1);
+                return;
+            }
+        }
+
         let lookahead = table.max_len(entry);
 
         if lookahead == 1 {
             if CollationTable::is_missing(entry) {
-                // Unlisted code points receive implicit weights.
-                self.queue_raw_weight(implicit_a(left_val));
-                self.queue_raw_weight(implicit_b(left_val));
+                // Unlisted code points receive implicit weights (or their rank in a tailoring's
+                // Han order).
+                for weights in implicit_weights(self.ctx.table, left_val) {
+                    self.queue_raw_weight(weights);
+                }
             } else {
                 // Simple one-code-point match.
                 self.queue_row(table.simple_row(entry));
@@ -201,14 +213,14 @@ impl<'a, S: CodePointSource> CeaCursor<'a, S> {
 
         // This is a contraction-start entry, but there's no following code point to match. Fall
         // back to its simple row.
-        if self.source.remaining() == 1 {
+        if self.source.available(2) == 1 {
             self.queue_row(table.simple_row(entry));
             self.source.consume(1);
             return;
         }
 
         // Try the longest contiguous contraction first, without looking past the end of the input.
-        let mut match_len = self.source.remaining().min(lookahead);
+        let mut match_len = self.source.available(lookahead);
 
         while match_len > 0 {
             // Contiguous contraction attempts failed. Fall back to the first code point, but first
@@ -219,7 +231,7 @@ impl<'a, S: CodePointSource> CeaCursor<'a, S> {
                 // A discontiguous contraction was found after a single-code-point fallback. Remove
                 // the later code point(s) so they aren't processed again.
                 if let Some((pull_index, pulled_two, new_row)) =
-                    try_pulled_contraction(self.ctx, entry, &mut self.source, match_len)
+                    try_pulled_contraction(table, self.ctx.cldr, entry, &mut self.source, match_len)
                 {
                     self.queue_row(new_row);
                     self.source.remove_pulled_lookahead(pull_index, pulled_two);
@@ -234,19 +246,12 @@ impl<'a, S: CodePointSource> CeaCursor<'a, S> {
                 return;
             }
 
-            // Try the current contiguous subset. The table supports contractions of length 2 or 3.
-            let row = match match_len {
-                2 => table.get2(entry, self.source.peek(1).unwrap()),
-                3 => table.get3(
-                    entry,
-                    self.source.peek(1).unwrap(),
-                    self.source.peek(2).unwrap(),
-                ),
-                _ => unreachable!(),
-            };
+            // Try the current contiguous subset, of whatever length the contraction allows.
+            let source = &mut self.source;
+            let row = table.get_seq(entry, (1..match_len).map(|i| source.peek(i).unwrap()));
 
             if let Some(row) = row {
-                // A two-code-point contraction can sometimes be extended by pulling in a later
+                // A contiguous contraction can sometimes be extended by pulling in a later
                 // combining mark, provided the canonical combining classes allow it.
                 if let Some(new_row) =
                     try_discontiguous_contraction(table, entry, &mut self.source, match_len)
@@ -1,8 +1,8 @@
 use crate::cea_source::CodePointSource;
-use crate::collator::CollationContext;
 use crate::consts::INCLUDED_UNASSIGNED;
 use crate::tables::CollationTable;
 use crate::weights::pack_weights;
+use alloc::vec::Vec;
 use unicode_canonical_combining_class::get_canonical_combining_class_u32 as get_ccc;
 
 pub fn implicit_a(cp: u32) -> u32 {
@@ -44,6 +44,18 @@ pub fn implicit_b(cp: u32) -> u32 {
     pack_weights(false, bbbb as u16, 0, 0)
 }
 
+// Weights for a code point that has no entry in the table: the lead primary and the code point's
+// rank, if the tailoring orders it among the Han characters; otherwise its implicit weights
+pub fn implicit_weights(table: &CollationTable, cp: u32) -> [u32; 2] {
+    match table.han.and_then(|han| Some((han.lead, han.get(cp)?))) {
+        Some((lead, rank)) => [
+            pack_weights(false, lead, 32, 2),
+            pack_weights(false, rank, 0, 0),
+        ],
+        None => [implicit_a(cp), implicit_b(cp)],
+    }
+}
+
 pub fn remove_pulled(char_vals: &mut Vec<u32>, i: usize, input_length: &mut usize, try_two: bool) {
     char_vals.remove(i);
     *input_length -= 1;
@@ -60,7 +72,8 @@ pub fn try_discontiguous_contraction<'a>(
     source: &mut impl CodePointSource,
     match_len: usize,
 ) -> Option<&'a [u32]> {
-    if !CollationTable::is_contraction(entry) || match_len != 2 {
+    if !CollationTable::is_contraction(entry) || match_len < 2 || match_len >= table.max_len(entry)
+    {
         return None;
     }
 
@@ -69,25 +82,27 @@ pub fn try_discontiguous_contraction<'a>(
     let ccc_b = get_ccc(next) as u8;
 
     if ccc_a > 0 && ccc_b > ccc_a {
-        table.get3(entry, source.peek(1).unwrap(), next)
+        let matched = (1..match_len).map(|i| source.peek(i).unwrap());
+        table.get_seq(entry, matched.chain([next]))
     } else {
         None
     }
 }
 
 pub fn try_pulled_contraction<'a>(
-    ctx: &'a CollationContext,
+    table: &'a CollationTable,
+    cldr: bool,
     entry: u64,
     source: &mut impl CodePointSource,
     match_len: usize,
 ) -> Option<(usize, bool, &'a [u32])> {
-    let mut try_offset = match source.remaining() - match_len {
+    let mut try_offset = match source.available(match_len + 3) - match_len {
         3.. => match_len + 2,
         2 => match_len + 1,
         _ => match_len,
     };
 
-    let mut try_two = (try_offset - match_len == 2) && ctx.cldr;
+    let mut try_two = (try_offset - match_len == 2) && cldr;
 
     while try_offset > match_len {
         if !ccc_sequence_ok(source, match_len, try_offset) {
@@ -97,13 +112,15 @@ pub fn try_pulled_contraction<'a>(
         }
 
         let new_row = if try_two {
-            ctx.table.get3(
+            table.get_seq(
                 entry,
-                source.peek(try_offset - 1).unwrap(),
-                source.peek(try_offset).unwrap(),
+                [
+                    source.peek(try_offset - 1).unwrap(),
+                    source.peek(try_offset).unwrap(),
+                ],
             )
         } else {
-            ctx.table.get2(entry, source.peek(try_offset).unwrap())
+            table.get_seq(entry, [source.peek(try_offset).unwrap()])
         };
 
         if let Some(new_row) = new_row {
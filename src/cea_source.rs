@@ -1,5 +1,6 @@
 use crate::cea_match::remove_pulled;
 use crate::consts::{DECOMP, FCD};
+use alloc::vec::Vec;
 use unicode_canonical_combining_class::get_canonical_combining_class_u32 as get_ccc;
 
 pub struct VecSource<'a> {
@@ -12,11 +13,14 @@ pub struct VecSource<'a> {
 
 pub trait CodePointSource {
     fn is_empty(&mut self) -> bool;
-    fn remaining(&mut self) -> usize;
+    // The number of code points left, counting no further than `limit`
+    fn available(&mut self, limit: usize) -> usize;
     fn is_blocked(&self) -> bool;
     #[cfg(feature = "pipeline-stats")]
     fn consumed(&self) -> usize;
     fn peek(&mut self, offset: usize) -> Option<u32>;
+    // The code point `back` places before the current position (0 being the last one consumed)
+    fn previous(&mut self, back: usize) -> Option<u32>;
     fn consume(&mut self, count: usize);
     fn remove_pulled_lookahead(&mut self, offset: usize, pulled_two: bool);
 }
@@ -38,8 +42,8 @@ impl CodePointSource for VecSource<'_> {
         self.pos >= self.len
     }
 
-    fn remaining(&mut self) -> usize {
-        self.len - self.pos
+    fn available(&mut self, limit: usize) -> usize {
+        (self.len - self.pos).min(limit)
     }
 
     fn is_blocked(&self) -> bool {
@@ -56,6 +60,11 @@ impl CodePointSource for VecSource<'_> {
         (index < self.len).then(|| self.chars[index])
     }
 
+    fn previous(&mut self, back: usize) -> Option<u32> {
+        let index = self.pos.checked_sub(back + 1)?;
+        Some(self.chars[index])
+    }
+
     fn consume(&mut self, count: usize) {
         self.pos += count;
     }
@@ -66,12 +75,17 @@ impl CodePointSource for VecSource<'_> {
     }
 }
 
+const UTF8_LOOKAHEAD: usize = 8;
+const UTF8_HISTORY: usize = 4;
+
 pub struct Utf8Source<'a> {
     bytes: &'a [u8],
     byte_pos: usize,
-    lookahead: [u32; 4],
-    lookahead_bytes: [usize; 4],
+    lookahead: [u32; UTF8_LOOKAHEAD],
+    lookahead_bytes: [usize; UTF8_LOOKAHEAD],
     lookahead_len: usize,
+    history: [u32; UTF8_HISTORY],
+    history_len: usize,
     blocked: bool,
     prev_trail_cc: u8,
 }
@@ -81,9 +95,11 @@ impl<'a> Utf8Source<'a> {
         Self {
             bytes,
             byte_pos: 0,
-            lookahead: [0; 4],
-            lookahead_bytes: [0; 4],
+            lookahead: [0; UTF8_LOOKAHEAD],
+            lookahead_bytes: [0; UTF8_LOOKAHEAD],
             lookahead_len: 0,
+            history: [0; UTF8_HISTORY],
+            history_len: 0,
             blocked: false,
             prev_trail_cc: 0,
         }
@@ -91,7 +107,10 @@ impl<'a> Utf8Source<'a> {
 
     fn fill(&mut self, offset: usize) {
         while self.lookahead_len <= offset && self.decoded_byte_end() < self.bytes.len() {
-            if self.blocked {
+            // Lookahead beyond the buffer (for an unusually long contraction) is left to the
+            // full fallback path.
+            if self.blocked || self.lookahead_len == UTF8_LOOKAHEAD {
+                self.blocked = true;
                 return;
             }
 
@@ -156,14 +175,9 @@ impl CodePointSource for Utf8Source<'_> {
         self.lookahead_len == 0 && self.byte_pos >= self.bytes.len()
     }
 
-    fn remaining(&mut self) -> usize {
-        self.fill(3);
-
-        if self.blocked || self.decoded_byte_end() == self.bytes.len() {
-            self.lookahead_len
-        } else {
-            self.lookahead_len.max(4)
-        }
+    fn available(&mut self, limit: usize) -> usize {
+        self.fill(limit - 1);
+        self.lookahead_len.min(limit)
     }
 
     fn is_blocked(&self) -> bool {
@@ -180,7 +194,31 @@ impl CodePointSource for Utf8Source<'_> {
         (offset < self.lookahead_len).then(|| self.lookahead[offset])
     }
 
+    fn previous(&mut self, back: usize) -> Option<u32> {
+        if back < self.history_len {
+            return Some(self.history[self.history_len - 1 - back]);
+        }
+
+        // Earlier code points have been forgotten; a longer prefix is left to the full fallback
+        // path.
+        if self.history_len == UTF8_HISTORY {
+            self.blocked = true;
+        }
+
+        None
+    }
+
     fn consume(&mut self, count: usize) {
+        for i in 0..count {
+            if self.history_len == UTF8_HISTORY {
+                self.history.copy_within(1.., 0);
+                self.history_len -= 1;
+            }
+
+            self.history[self.history_len] = self.lookahead[i];
+            self.history_len += 1;
+        }
+
         self.byte_pos += self.lookahead_bytes[..count].iter().sum::<usize>();
         self.lookahead.copy_within(count..self.lookahead_len, 0);
         self.lookahead_bytes
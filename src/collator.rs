@@ -1,16 +1,95 @@
+use crate::TableVersion;
 use crate::ascii::{
-    AsciiResult, compare_ascii_primary_non_ignorable, fill_codepoints_and_compare_ascii,
+    AsciiResult, compare_ascii_primary_non_ignorable, fill_codepoints,
+    fill_codepoints_and_compare_ascii,
 };
 use crate::cea::{LazyPrimaryResult, compare_primary_streaming, compare_primary_streaming_utf8};
+#[cfg(feature = "bengali")]
+use crate::consts::BENGALI;
+#[cfg(any(feature = "russian", feature = "bulgarian"))]
+use crate::consts::CYRILLIC_SCRIPT;
+#[cfg(feature = "greek")]
+use crate::consts::GREEK;
+#[cfg(feature = "hebrew")]
+use crate::consts::HEBREW;
+#[cfg(feature = "hindi")]
+use crate::consts::HINDI;
+#[cfg(feature = "japanese")]
+use crate::consts::JAPANESE;
+#[cfg(feature = "korean")]
+use crate::consts::KOREAN;
+#[cfg(feature = "lao")]
+use crate::consts::LAO;
+#[cfg(feature = "pashto")]
+use crate::consts::PASHTO;
+#[cfg(feature = "persian")]
+use crate::consts::PERSIAN;
+#[cfg(feature = "serbian")]
+use crate::consts::SERBIAN;
+#[cfg(feature = "tamil")]
+use crate::consts::TAMIL;
+#[cfg(feature = "thai")]
+use crate::consts::THAI;
+#[cfg(feature = "ukrainian")]
+use crate::consts::UKRAINIAN;
+#[cfg(feature = "urdu")]
+use crate::consts::URDU;
 use crate::consts::{ARABIC_INTERLEAVED, ARABIC_SCRIPT, CLDR_ROOT, DUCET, LOW_CLDR, LOW_DUCET};
+#[cfg(feature = "azerbaijani")]
+use crate::consts::{AZERBAIJANI, LOW_AZERBAIJANI};
+#[cfg(feature = "chinese")]
+use crate::consts::{
+    CHINESE_PINYIN, CHINESE_STROKE, CHINESE_ZHUYIN, LOW_CHINESE_PINYIN, LOW_CHINESE_STROKE,
+    LOW_CHINESE_ZHUYIN,
+};
+#[cfg(feature = "cldr-43")]
+use crate::consts::{CLDR_CLDR_43, LOW_CLDR_CLDR_43};
+#[cfg(feature = "cldr-44")]
+use crate::consts::{CLDR_CLDR_44, DUCET_CLDR_44, LOW_CLDR_CLDR_44, LOW_DUCET_CLDR_44};
+#[cfg(feature = "unicode-14")]
+use crate::consts::{CLDR_UNICODE_14, DUCET_UNICODE_14, LOW_CLDR_UNICODE_14, LOW_DUCET_UNICODE_14};
+#[cfg(feature = "unicode-15")]
+use crate::consts::{CLDR_UNICODE_15, LOW_CLDR_UNICODE_15};
+#[cfg(feature = "czech")]
+use crate::consts::{CZECH, LOW_CZECH};
+#[cfg(feature = "danish")]
+use crate::consts::{DANISH, LOW_DANISH};
+#[cfg(any(feature = "unicode-15", feature = "cldr-43"))]
+use crate::consts::{DUCET_UNICODE_15, LOW_DUCET_UNICODE_15};
+#[cfg(feature = "emoji")]
+use crate::consts::{EMOJI, LOW_EMOJI};
+#[cfg(feature = "finnish")]
+use crate::consts::{FINNISH, LOW_FINNISH};
+#[cfg(feature = "german-phonebook")]
+use crate::consts::{GERMAN_PHONEBOOK, LOW_GERMAN_PHONEBOOK};
+#[cfg(feature = "hungarian")]
+use crate::consts::{HUNGARIAN, LOW_HUNGARIAN};
+#[cfg(feature = "lithuanian")]
+use crate::consts::{LITHUANIAN, LOW_LITHUANIAN};
+#[cfg(feature = "norwegian")]
+use crate::consts::{LOW_NORWEGIAN, NORWEGIAN};
+#[cfg(feature = "polish")]
+use crate::consts::{LOW_POLISH, POLISH};
+#[cfg(feature = "slovak")]
+use crate::consts::{LOW_SLOVAK, SLOVAK};
+#[cfg(feature = "spanish")]
+use crate::consts::{LOW_SPANISH, LOW_SPANISH_TRADITIONAL, SPANISH, SPANISH_TRADITIONAL};
+#[cfg(feature = "swedish")]
+use crate::consts::{LOW_SWEDISH, SWEDISH};
+#[cfg(feature = "turkish")]
+use crate::consts::{LOW_TURKISH, TURKISH};
+#[cfg(feature = "vietnamese")]
+use crate::consts::{LOW_VIETNAMESE, VIETNAMESE};
 use crate::first_weight::try_initial;
 use crate::normalize::make_nfd;
 use crate::prefix::{find_byte_prefix, find_prefix_shifted};
 use crate::sort_key::compare_incremental;
-use crate::tables::CollationTable;
+use crate::tables::{CollationTable, LOW_DEFER};
 use crate::{Locale, Tailoring};
+use alloc::vec;
+use alloc::vec::Vec;
 use bstr::{B, ByteSlice};
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 const LAZY_UTF8_PRIMARY_MIN_COMBINED_BYTES: usize = 64;
 
@@ -94,8 +173,10 @@ impl PipelineStats {
 /// there is a further choice of locale tailoring. The `Root` locale represents the table in its
 /// unmodified form. The `ArabicScript` locale shifts the weights of Arabic-script letters so that
 /// they sort before the Latin script; and the `ArabicInterleaved` locale mixes the two scripts, so
-/// that, e.g., _alif_ sorts between A and B, and _bā’_ between B and C. Further locales will be
-/// added over time.
+/// that, e.g., _alif_ sorts between A and B, and _bā’_ between B and C. The `GermanPhonebook`
+/// locale sorts "ä," "ö," and "ü" as "ae," "oe," and "ue"; and there are further locales for other
+/// languages (e.g., `Swedish`, in which "å," "ä," and "ö" are separate letters after "z"). More
+/// will be added over time.
 ///
 /// You can also choose between two approaches to the handling of variable-weight characters:
 /// "non-ignorable" and "shifted." Finally, you can select whether to use byte-value comparison as a
@@ -274,12 +355,21 @@ impl Collator {
 
         // While iterating through input strings and filling code point Vecs, try to get a result by
         // comparing ASCII characters. This can avoid a lot of computation.
-        let ascii_result = fill_codepoints_and_compare_ascii(
-            &mut a_iter,
-            &mut b_iter,
-            &mut self.a_chars,
-            &mut self.b_chars,
-        );
+        let ascii_result = if ascii_order_preserved(self.tailoring) {
+            fill_codepoints_and_compare_ascii(
+                &mut a_iter,
+                &mut b_iter,
+                &mut self.a_chars,
+                &mut self.b_chars,
+            )
+        } else {
+            fill_codepoints(
+                &mut a_iter,
+                &mut b_iter,
+                &mut self.a_chars,
+                &mut self.b_chars,
+            )
+        };
 
         #[cfg(feature = "pipeline-stats")]
         {
@@ -382,7 +472,7 @@ impl Collator {
             self.stats.later_levels_reached += 1;
         }
 
-        let comparison = compare_incremental(&self.a_cea, &self.b_cea, ctx.shifting);
+        let comparison = compare_incremental(&self.a_cea, &self.b_cea, ctx);
 
         if comparison == Ordering::Equal && self.tiebreak {
             #[cfg(feature = "pipeline-stats")]
@@ -406,31 +496,174 @@ fn has_byte_prefix(a: &[u8], b: &[u8]) -> bool {
     a.first().zip(b.first()).is_some_and(|(x, y)| x == y)
 }
 
+#[allow(clippy::struct_excessive_bools)]
 pub struct CollationContext {
     pub shifting: bool,
     pub cldr: bool,
+    pub upper_first: bool,
+    pub hiragana_quaternary: bool,
     pub table: &'static CollationTable,
     pub low: &'static [u32],
 }
 
 impl CollationContext {
     fn new(shifting: bool, tailoring: Tailoring) -> Self {
-        let cldr = tailoring != Tailoring::Ducet;
+        let cldr = !matches!(tailoring, Tailoring::Ducet | Tailoring::DucetLegacy(_));
+
+        let (table, low) = get_collation_table(tailoring);
 
         Self {
             shifting,
             cldr,
-            table: get_collation_table(tailoring),
-            low: if cldr { &LOW_CLDR } else { &LOW_DUCET },
+            upper_first: upper_first(tailoring),
+            hiragana_quaternary: hiragana_quaternary(tailoring),
+            table,
+            low,
         }
     }
+
+    // Weights from the fast-path array, for low code points that the table doesn't have to handle
+    pub fn low_weights(&self, code_point: u32) -> Option<u32> {
+        self.low
+            .get(code_point as usize)
+            .copied()
+            .filter(|&weights| weights != LOW_DEFER)
+    }
+}
+
+// Whether a tailoring keeps the root order of ASCII letters and digits, with lowercase before
+// uppercase. Otherwise the ASCII fast path in `collate` can't be used: e.g., Danish sorts "aa" as a
+// single letter after "z," and puts uppercase first.
+fn ascii_order_preserved(tailoring: Tailoring) -> bool {
+    let (table, _) = get_collation_table(tailoring);
+    !table.tailors_ascii && !upper_first(tailoring)
+}
+
+// Whether a tailoring sorts uppercase letters before lowercase ones
+const fn upper_first(tailoring: Tailoring) -> bool {
+    match tailoring {
+        #[cfg(feature = "danish")]
+        Tailoring::Cldr(Locale::Danish) => true,
+        _ => false,
+    }
+}
+
+// Whether a tailoring compares hiragana and katakana at a level of their own, after the tertiary
+const fn hiragana_quaternary(tailoring: Tailoring) -> bool {
+    match tailoring {
+        #[cfg(feature = "japanese")]
+        Tailoring::Cldr(Locale::JapaneseHiraganaQuaternary) => true,
+        _ => false,
+    }
 }
 
-fn get_collation_table(tailoring: Tailoring) -> &'static CollationTable {
+pub fn get_collation_table(tailoring: Tailoring) -> (&'static CollationTable, &'static [u32]) {
     match tailoring {
-        Tailoring::Cldr(Locale::ArabicScript) => &ARABIC_SCRIPT,
-        Tailoring::Cldr(Locale::ArabicInterleaved) => &ARABIC_INTERLEAVED,
-        Tailoring::Cldr(Locale::Root) => &CLDR_ROOT,
-        Tailoring::Ducet => &DUCET,
+        Tailoring::Cldr(Locale::ArabicScript) => (&ARABIC_SCRIPT, &LOW_CLDR),
+        Tailoring::Cldr(Locale::ArabicInterleaved) => (&ARABIC_INTERLEAVED, &LOW_CLDR),
+        #[cfg(feature = "german-phonebook")]
+        Tailoring::Cldr(Locale::GermanPhonebook) => (&GERMAN_PHONEBOOK, &LOW_GERMAN_PHONEBOOK),
+        #[cfg(feature = "swedish")]
+        Tailoring::Cldr(Locale::Swedish) => (&SWEDISH, &LOW_SWEDISH),
+        #[cfg(feature = "finnish")]
+        Tailoring::Cldr(Locale::Finnish) => (&FINNISH, &LOW_FINNISH),
+        #[cfg(feature = "danish")]
+        Tailoring::Cldr(Locale::Danish) => (&DANISH, &LOW_DANISH),
+        #[cfg(feature = "norwegian")]
+        Tailoring::Cldr(Locale::NorwegianBokmal | Locale::NorwegianNynorsk) => {
+            (&NORWEGIAN, &LOW_NORWEGIAN)
+        }
+        #[cfg(feature = "spanish")]
+        Tailoring::Cldr(Locale::Spanish) => (&SPANISH, &LOW_SPANISH),
+        #[cfg(feature = "spanish")]
+        Tailoring::Cldr(Locale::SpanishTraditional) => {
+            (&SPANISH_TRADITIONAL, &LOW_SPANISH_TRADITIONAL)
+        }
+        #[cfg(feature = "turkish")]
+        Tailoring::Cldr(Locale::Turkish) => (&TURKISH, &LOW_TURKISH),
+        #[cfg(feature = "azerbaijani")]
+        Tailoring::Cldr(Locale::Azerbaijani) => (&AZERBAIJANI, &LOW_AZERBAIJANI),
+        #[cfg(feature = "polish")]
+        Tailoring::Cldr(Locale::Polish) => (&POLISH, &LOW_POLISH),
+        #[cfg(feature = "czech")]
+        Tailoring::Cldr(Locale::Czech) => (&CZECH, &LOW_CZECH),
+        #[cfg(feature = "slovak")]
+        Tailoring::Cldr(Locale::Slovak) => (&SLOVAK, &LOW_SLOVAK),
+        #[cfg(feature = "persian")]
+        Tailoring::Cldr(Locale::Persian) => (&PERSIAN, &LOW_CLDR),
+        #[cfg(feature = "urdu")]
+        Tailoring::Cldr(Locale::Urdu) => (&URDU, &LOW_CLDR),
+        #[cfg(feature = "pashto")]
+        Tailoring::Cldr(Locale::Pashto) => (&PASHTO, &LOW_CLDR),
+        #[cfg(feature = "lithuanian")]
+        Tailoring::Cldr(Locale::Lithuanian) => (&LITHUANIAN, &LOW_LITHUANIAN),
+        #[cfg(feature = "vietnamese")]
+        Tailoring::Cldr(Locale::Vietnamese) => (&VIETNAMESE, &LOW_VIETNAMESE),
+        #[cfg(feature = "chinese")]
+        Tailoring::Cldr(Locale::ChinesePinyin) => (&CHINESE_PINYIN, &LOW_CHINESE_PINYIN),
+        #[cfg(feature = "chinese")]
+        Tailoring::Cldr(Locale::ChineseStroke) => (&CHINESE_STROKE, &LOW_CHINESE_STROKE),
+        #[cfg(feature = "chinese")]
+        Tailoring::Cldr(Locale::ChineseZhuyin) => (&CHINESE_ZHUYIN, &LOW_CHINESE_ZHUYIN),
+        #[cfg(feature = "japanese")]
+        Tailoring::Cldr(Locale::Japanese | Locale::JapaneseHiraganaQuaternary) => {
+            (&JAPANESE, &LOW_CLDR)
+        }
+        #[cfg(feature = "korean")]
+        Tailoring::Cldr(Locale::Korean) => (&KOREAN, &LOW_CLDR),
+        #[cfg(feature = "hindi")]
+        Tailoring::Cldr(Locale::Hindi) => (&HINDI, &LOW_CLDR),
+        #[cfg(feature = "bengali")]
+        Tailoring::Cldr(Locale::Bengali) => (&BENGALI, &LOW_CLDR),
+        #[cfg(feature = "tamil")]
+        Tailoring::Cldr(Locale::Tamil) => (&TAMIL, &LOW_CLDR),
+        #[cfg(feature = "hungarian")]
+        Tailoring::Cldr(Locale::Hungarian) => (&HUNGARIAN, &LOW_HUNGARIAN),
+        #[cfg(feature = "emoji")]
+        Tailoring::Cldr(Locale::Emoji) => (&EMOJI, &LOW_EMOJI),
+        #[cfg(feature = "greek")]
+        Tailoring::Cldr(Locale::Greek) => (&GREEK, &LOW_CLDR),
+        #[cfg(feature = "russian")]
+        Tailoring::Cldr(Locale::Russian) => (&CYRILLIC_SCRIPT, &LOW_CLDR),
+        #[cfg(feature = "bulgarian")]
+        Tailoring::Cldr(Locale::Bulgarian) => (&CYRILLIC_SCRIPT, &LOW_CLDR),
+        #[cfg(feature = "ukrainian")]
+        Tailoring::Cldr(Locale::Ukrainian) => (&UKRAINIAN, &LOW_CLDR),
+        #[cfg(feature = "serbian")]
+        Tailoring::Cldr(Locale::Serbian) => (&SERBIAN, &LOW_CLDR),
+        #[cfg(feature = "hebrew")]
+        Tailoring::Cldr(Locale::Hebrew) => (&HEBREW, &LOW_CLDR),
+        #[cfg(feature = "thai")]
+        Tailoring::Cldr(Locale::Thai) => (&THAI, &LOW_CLDR),
+        #[cfg(feature = "lao")]
+        Tailoring::Cldr(Locale::Lao) => (&LAO, &LOW_CLDR),
+        Tailoring::Cldr(Locale::Root | Locale::German) => (&CLDR_ROOT, &LOW_CLDR),
+        Tailoring::Ducet => (&DUCET, &LOW_DUCET),
+        Tailoring::CldrLegacy(version) => get_legacy_table(version, true),
+        Tailoring::DucetLegacy(version) => get_legacy_table(version, false),
+    }
+}
+
+const fn get_legacy_table(
+    version: TableVersion,
+    cldr: bool,
+) -> (&'static CollationTable, &'static [u32]) {
+    match (version, cldr) {
+        #[cfg(feature = "unicode-14")]
+        (TableVersion::Unicode14, true) => (&CLDR_UNICODE_14, &LOW_CLDR_UNICODE_14),
+        #[cfg(feature = "unicode-14")]
+        (TableVersion::Unicode14, false) => (&DUCET_UNICODE_14, &LOW_DUCET_UNICODE_14),
+        #[cfg(feature = "unicode-15")]
+        (TableVersion::Unicode15, true) => (&CLDR_UNICODE_15, &LOW_CLDR_UNICODE_15),
+        #[cfg(feature = "unicode-15")]
+        (TableVersion::Unicode15, false) => (&DUCET_UNICODE_15, &LOW_DUCET_UNICODE_15),
+        #[cfg(feature = "cldr-43")]
+        (TableVersion::Cldr43, true) => (&CLDR_CLDR_43, &LOW_CLDR_CLDR_43),
+        #[cfg(feature = "cldr-43")]
+        (TableVersion::Cldr43, false) => (&DUCET_UNICODE_15, &LOW_DUCET_UNICODE_15),
+        #[cfg(feature = "cldr-44")]
+        (TableVersion::Cldr44, true) => (&CLDR_CLDR_44, &LOW_CLDR_CLDR_44),
+        #[cfg(feature = "cldr-44")]
+        (TableVersion::Cldr44, false) => (&DUCET_CLDR_44, &LOW_DUCET_CLDR_44),
     }
 }
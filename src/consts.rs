@@ -1,5 +1,10 @@
-use crate::tables::{CollationTable, DecompTable, FcdTable, VariableTable};
-use std::sync::LazyLock;
+use crate::tables::{
+    CollationTable, ContractionEdge, ContractionMeta, DecompTable, FcdTable, LOW_DEFER,
+    VariableTable,
+};
+// Needed only by some of the locale tailorings, which may all be disabled
+#[allow(unused_imports)]
+use crate::tables::{HanTable, PrefixMapping, defer_low};
 
 //
 // Const
@@ -14,17 +19,17 @@ pub const INCLUDED_UNASSIGNED: [u32; 4] = [0x2B73A, 0x2B81E, 0x2CEA2, 0x2EBE1];
 //
 
 // Map a code point to its canonical decomposition (if any)
-const DECOMP_DATA: &[u8] = include_bytes!("data/decomp");
-pub static DECOMP: LazyLock<DecompTable> =
-    LazyLock::new(|| postcard::from_bytes(DECOMP_DATA).unwrap());
+#[allow(clippy::unreadable_literal)]
+pub static DECOMP: DecompTable = include!(concat!(env!("OUT_DIR"), "/decomp.rs"));
 
 // Map a code point to the first and last CCCs (two u8s packed into a u16) of its canonical
 // decomposition (if any)
-const FCD_DATA: &[u8] = include_bytes!("data/fcd");
-pub static FCD: LazyLock<FcdTable> = LazyLock::new(|| postcard::from_bytes(FCD_DATA).unwrap());
+#[allow(clippy::unreadable_literal)]
+pub static FCD: FcdTable = include!(concat!(env!("OUT_DIR"), "/fcd.rs"));
 
 // Map a low code point to its collation weights (DUCET)
-// Code points are used to index into this array
+// Code points are used to index into this array; `LOW_DEFER` marks those that must be looked up in
+// the main table instead (i.e., L and l, which start contractions)
 #[allow(clippy::unreadable_literal)]
 pub const LOW_DUCET: [u32; 183] = [
     0, 0, 0, 0, 0, 0, 0, 0, 0, 33653792, 33719328, 33784864, 33850400, 33915936, 0, 0, 0, 0, 0, 0,
@@ -33,23 +38,22 @@ pub const LOW_DUCET: [u32; 183] = [
     42107936, 63144992, 561841184, 561906720, 561972256, 562037792, 562103328, 562168864,
     562234400, 562299936, 562365472, 562431008, 37913632, 37520416, 112821280, 112886816,
     112952352, 40928288, 62751776, 595595296, 597299232, 599003168, 600444960, 602345504,
-    606212128, 607195168, 609751072, 611520544, 613355552, 614993952, 0, 620105760, 621088800,
-    623644704, 626790432, 628166688, 629411872, 633737248, 636555296, 638849056, 641994784,
-    643174432, 643829792, 644616224, 646058016, 54494240, 63210528, 54559776, 82019360, 34309152,
-    81822752, 595592224, 597296160, 599000096, 600441888, 602342432, 606209056, 607192096,
-    609748000, 611517472, 613352480, 614990880, 0, 620102688, 621085728, 623641632, 626787360,
-    628163616, 629408800, 633734176, 636552224, 638845984, 641991712, 643171360, 643826720,
-    644613152, 646054944, 54625312, 113083424, 54690848, 113214496, 0, 0, 0, 0, 0, 0, 33981472, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34190880, 40535072,
-    558367776, 558498848, 558302240, 558564384, 113148960, 62358560, 82281504, 102794272,
-    595601440, 54232096, 113017888, 0, 102925344, 82084896, 90407968, 112624672, 561981472,
-    562047008, 81888288, 657590304, 62489632,
+    606212128, 607195168, 609751072, 611520544, 613355552, 614993952, LOW_DEFER, 620105760,
+    621088800, 623644704, 626790432, 628166688, 629411872, 633737248, 636555296, 638849056,
+    641994784, 643174432, 643829792, 644616224, 646058016, 54494240, 63210528, 54559776, 82019360,
+    34309152, 81822752, 595592224, 597296160, 599000096, 600441888, 602342432, 606209056,
+    607192096, 609748000, 611517472, 613352480, 614990880, LOW_DEFER, 620102688, 621085728,
+    623641632, 626787360, 628163616, 629408800, 633734176, 636552224, 638845984, 641991712,
+    643171360, 643826720, 644613152, 646054944, 54625312, 113083424, 54690848, 113214496, 0, 0, 0,
+    0, 0, 0, 33981472, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 34190880, 40535072, 558367776, 558498848, 558302240, 558564384, 113148960, 62358560,
+    82281504, 102794272, 595601440, 54232096, 113017888, 0, 102925344, 82084896, 90407968,
+    112624672, 561981472, 562047008, 81888288, 657590304, 62489632,
 ];
 
 // Map non-low code points to their single-code-point weights and contraction metadata (DUCET)
-const DUCET_DATA: &[u8] = include_bytes!("data/ducet");
-pub static DUCET: LazyLock<CollationTable> =
-    LazyLock::new(|| postcard::from_bytes(DUCET_DATA).unwrap());
+#[allow(clippy::unreadable_literal)]
+pub static DUCET: CollationTable = include!(concat!(env!("OUT_DIR"), "/ducet.rs"));
 
 // Map a low code point to its collation weights (CLDR)
 // Code points are used to index into this array
@@ -61,35 +65,585 @@ pub const LOW_CLDR: [u32; 183] = [
     42107936, 63144992, 561841184, 561906720, 561972256, 562037792, 562103328, 562168864,
     562234400, 562299936, 562365472, 562431008, 37913632, 37520416, 112788512, 112854048,
     112919584, 40928288, 62751776, 662704160, 664473632, 666177568, 667619360, 669519904,
-    673386528, 674369568, 676859936, 678629408, 680464416, 682102816, 0, 687214624, 688197664,
-    690753568, 693899296, 695275552, 696520736, 700846112, 703664160, 705957920, 709103648,
-    710283296, 710938656, 711725088, 713166880, 54494240, 63210528, 54559776, 81986592, 34309152,
-    81789984, 662701088, 664470560, 666174496, 667616288, 669516832, 673383456, 674366496,
-    676856864, 678626336, 680461344, 682099744, 0, 687211552, 688194592, 690750496, 693896224,
-    695272480, 696517664, 700843040, 703661088, 705954848, 709100576, 710280224, 710935584,
-    711722016, 713163808, 54625312, 113050656, 54690848, 113181728, 0, 0, 0, 0, 0, 0, 33981472, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34190880, 40535072,
-    558367776, 558498848, 558302240, 558564384, 113116192, 62358560, 82248736, 102761504,
-    662710304, 54232096, 112985120, 0, 102892576, 82052128, 90375200, 112591904, 561981472,
-    562047008, 81855520, 724699168, 62489632,
+    673386528, 674369568, 676859936, 678629408, 680464416, 682102816, LOW_DEFER, 687214624,
+    688197664, 690753568, 693899296, 695275552, 696520736, 700846112, 703664160, 705957920,
+    709103648, 710283296, 710938656, 711725088, 713166880, 54494240, 63210528, 54559776, 81986592,
+    34309152, 81789984, 662701088, 664470560, 666174496, 667616288, 669516832, 673383456,
+    674366496, 676856864, 678626336, 680461344, 682099744, LOW_DEFER, 687211552, 688194592,
+    690750496, 693896224, 695272480, 696517664, 700843040, 703661088, 705954848, 709100576,
+    710280224, 710935584, 711722016, 713163808, 54625312, 113050656, 54690848, 113181728, 0, 0, 0,
+    0, 0, 0, 33981472, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 34190880, 40535072, 558367776, 558498848, 558302240, 558564384, 113116192, 62358560,
+    82248736, 102761504, 662710304, 54232096, 112985120, 0, 102892576, 82052128, 90375200,
+    112591904, 561981472, 562047008, 81855520, 724699168, 62489632,
 ];
 
 // Map non-low code points to their single-code-point weights and contraction metadata (CLDR)
-const CLDR_ROOT_DATA: &[u8] = include_bytes!("data/cldr_root");
-pub static CLDR_ROOT: LazyLock<CollationTable> =
-    LazyLock::new(|| postcard::from_bytes(CLDR_ROOT_DATA).unwrap());
+#[allow(clippy::unreadable_literal)]
+pub static CLDR_ROOT: CollationTable = include!(concat!(env!("OUT_DIR"), "/cldr_root.rs"));
 
 // CLDR root collation with Arabic-script characters sorted before Latin-script characters
-const ARABIC_SCRIPT_DATA: &[u8] = include_bytes!("data/tailoring/arabic_script");
-pub static ARABIC_SCRIPT: LazyLock<CollationTable> =
-    LazyLock::new(|| postcard::from_bytes(ARABIC_SCRIPT_DATA).unwrap());
+#[allow(clippy::unreadable_literal)]
+pub static ARABIC_SCRIPT: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_arabic_script.rs"));
 
 // CLDR root collation with Arabic-script characters interleaved among Latin-script characters
-const ARABIC_INTERLEAVED_DATA: &[u8] = include_bytes!("data/tailoring/arabic_interleaved");
-pub static ARABIC_INTERLEAVED: LazyLock<CollationTable> =
-    LazyLock::new(|| postcard::from_bytes(ARABIC_INTERLEAVED_DATA).unwrap());
-
-// Code points that have either a variable weight, or a primary weight of zero
-const VARIABLE_DATA: &[u8] = include_bytes!("data/variable");
-pub static VARIABLE: LazyLock<VariableTable> =
-    LazyLock::new(|| postcard::from_bytes(VARIABLE_DATA).unwrap());
+#[allow(clippy::unreadable_literal)]
+pub static ARABIC_INTERLEAVED: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_arabic_interleaved.rs"));
+
+// The locale tailorings below are each compiled in only with the Cargo feature of their language
+// (all of them are enabled by default, through the `locales` feature)
+
+// German phonebook order (CLDR `de-u-co-phonebk`), in which ä, ö and ü sort as ae, oe and ue;
+// stored as an overlay on the CLDR root table
+#[cfg(feature = "german-phonebook")]
+#[allow(clippy::unreadable_literal)]
+pub static GERMAN_PHONEBOOK: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_german_phonebook.rs"))
+        .with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for German phonebook order: a, o and u (and their capitals) start contractions
+// with the combining diaeresis, so they have to be looked up in the table
+#[cfg(feature = "german-phonebook")]
+pub static LOW_GERMAN_PHONEBOOK: [u32; 183] = defer_low(LOW_CLDR, &GERMAN_PHONEBOOK);
+
+// Swedish: å, ä and ö are separate letters after z, and ü sorts with y
+#[cfg(feature = "swedish")]
+#[allow(clippy::unreadable_literal)]
+pub static SWEDISH: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_swedish.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Swedish, deferring to the table for the letters that start contractions
+// with combining marks (e.g., a + ring above)
+#[cfg(feature = "swedish")]
+pub static LOW_SWEDISH: [u32; 183] = defer_low(LOW_CLDR, &SWEDISH);
+
+// Finnish: å, ä and ö are separate letters after z, and ü sorts with y
+#[cfg(feature = "finnish")]
+#[allow(clippy::unreadable_literal)]
+pub static FINNISH: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_finnish.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Finnish, deferring to the table for the letters that start contractions
+// with combining marks
+#[cfg(feature = "finnish")]
+pub static LOW_FINNISH: [u32; 183] = defer_low(LOW_CLDR, &FINNISH);
+
+// Danish: æ, ø and å are separate letters after z, and "aa" sorts as å
+#[cfg(feature = "danish")]
+#[allow(clippy::unreadable_literal)]
+pub static DANISH: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_danish.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Danish, deferring to the table for the letters that start contractions
+// (including a and A, for "aa")
+#[cfg(feature = "danish")]
+pub static LOW_DANISH: [u32; 183] = defer_low(LOW_CLDR, &DANISH);
+
+// Norwegian (both Bokmål and Nynorsk): æ, ø and å are separate letters after z, and "aa" sorts
+// as å
+#[cfg(feature = "norwegian")]
+#[allow(clippy::unreadable_literal)]
+pub static NORWEGIAN: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_norwegian.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Norwegian, deferring to the table for the letters that start contractions
+#[cfg(feature = "norwegian")]
+pub static LOW_NORWEGIAN: [u32; 183] = defer_low(LOW_CLDR, &NORWEGIAN);
+
+// Spanish: ñ is a separate letter after n
+#[cfg(feature = "spanish")]
+#[allow(clippy::unreadable_literal)]
+pub static SPANISH: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_spanish.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Spanish, deferring to the table for n and N (which start contractions with
+// the combining tilde)
+#[cfg(feature = "spanish")]
+pub static LOW_SPANISH: [u32; 183] = defer_low(LOW_CLDR, &SPANISH);
+
+// Traditional Spanish: ñ is a separate letter after n, and "ch" and "ll" are letters after c and
+// l, respectively
+#[cfg(feature = "spanish")]
+#[allow(clippy::unreadable_literal)]
+pub static SPANISH_TRADITIONAL: CollationTable = include!(concat!(
+    env!("OUT_DIR"),
+    "/tailoring_spanish_traditional.rs"
+))
+.with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for traditional Spanish, deferring to the table for c, l and n (and their
+// capitals), which start contractions
+#[cfg(feature = "spanish")]
+pub static LOW_SPANISH_TRADITIONAL: [u32; 183] = defer_low(LOW_CLDR, &SPANISH_TRADITIONAL);
+
+// Turkish: ç, ğ, ı, ö, ş and ü are separate letters, and I is the capital of ı (with İ the capital
+// of i)
+#[cfg(feature = "turkish")]
+#[allow(clippy::unreadable_literal)]
+pub static TURKISH: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_turkish.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Turkish, deferring to the table for I and for the letters that start
+// contractions with combining marks
+#[cfg(feature = "turkish")]
+pub static LOW_TURKISH: [u32; 183] = defer_low(LOW_CLDR, &TURKISH);
+
+// Azerbaijani: as Turkish, but also with q after k, x after h, and w after z
+#[cfg(feature = "azerbaijani")]
+#[allow(clippy::unreadable_literal)]
+pub static AZERBAIJANI: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_azerbaijani.rs"))
+        .with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Azerbaijani, deferring to the table for I, q, w and x (and their capitals),
+// and for the letters that start contractions with combining marks
+#[cfg(feature = "azerbaijani")]
+pub static LOW_AZERBAIJANI: [u32; 183] = defer_low(LOW_CLDR, &AZERBAIJANI);
+
+// Polish: ą, ć, ę, ł, ń, ó, ś, ź and ż are separate letters
+#[cfg(feature = "polish")]
+#[allow(clippy::unreadable_literal)]
+pub static POLISH: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_polish.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Polish, deferring to the table for the letters that start contractions with
+// combining marks
+#[cfg(feature = "polish")]
+pub static LOW_POLISH: [u32; 183] = defer_low(LOW_CLDR, &POLISH);
+
+// Czech: č, ř, š and ž are separate letters, and "ch" is a letter after h
+#[cfg(feature = "czech")]
+#[allow(clippy::unreadable_literal)]
+pub static CZECH: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_czech.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Czech, deferring to the table for c and C (which start the "ch" contraction)
+// and for the letters that start contractions with combining marks
+#[cfg(feature = "czech")]
+pub static LOW_CZECH: [u32; 183] = defer_low(LOW_CLDR, &CZECH);
+
+// Slovak: ä, č, ô, ř, š and ž are separate letters, and "ch" is a letter after h
+#[cfg(feature = "slovak")]
+#[allow(clippy::unreadable_literal)]
+pub static SLOVAK: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_slovak.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Slovak, deferring to the table for c and C (which start the "ch" contraction)
+// and for the letters that start contractions with combining marks
+#[cfg(feature = "slovak")]
+pub static LOW_SLOVAK: [u32; 183] = defer_low(LOW_CLDR, &SLOVAK);
+
+// Persian (CLDR `fa`): Arabic-script letters sort before the Latin script, with the Persian order
+// of the alif and hamza forms, and with Arabic kāf and yā’ as variants of their Persian forms
+#[cfg(feature = "persian")]
+#[allow(clippy::unreadable_literal)]
+pub static PERSIAN: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_persian.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Urdu: Arabic-script letters sort before the Latin script, in the Urdu alphabetical order, with
+// the aspirated digraphs (formed with dō-chashmī he) as letters of their own
+#[cfg(feature = "urdu")]
+#[allow(clippy::unreadable_literal)]
+pub static URDU: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_urdu.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Pashto: Arabic-script letters sort before the Latin script, in the Pashto alphabetical order
+#[cfg(feature = "pashto")]
+#[allow(clippy::unreadable_literal)]
+pub static PASHTO: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_pashto.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Lithuanian: y is a secondary variant of i; the letters with ogonek, caron, dot above, and macron
+// follow their base letters; and a combining dot above on a soft-dotted i is absorbed by a
+// following accent
+#[cfg(feature = "lithuanian")]
+#[allow(clippy::unreadable_literal)]
+pub static LITHUANIAN: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_lithuanian.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Lithuanian, deferring to the table for y and Y and for the letters that start
+// contractions with combining marks
+#[cfg(feature = "lithuanian")]
+pub static LOW_LITHUANIAN: [u32; 183] = defer_low(LOW_CLDR, &LITHUANIAN);
+
+// Vietnamese: ă, â, đ, ê, ô, ơ, and ư are separate letters, and the tone marks are ordered at the
+// secondary level as grave, hook above, tilde, acute, dot below
+#[cfg(feature = "vietnamese")]
+#[allow(clippy::unreadable_literal)]
+pub static VIETNAMESE: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_vietnamese.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Vietnamese, deferring to the table for the letters that start contractions
+// with combining marks
+#[cfg(feature = "vietnamese")]
+pub static LOW_VIETNAMESE: [u32; 183] = defer_low(LOW_CLDR, &VIETNAMESE);
+
+// The Han orders of the Chinese tailorings, as ranks following a lead primary that sorts before the
+// Latin script
+#[cfg(feature = "chinese")]
+pub static HAN_PINYIN: HanTable = include!(concat!(env!("OUT_DIR"), "/han_pinyin.rs"));
+#[cfg(feature = "chinese")]
+pub static HAN_STROKE: HanTable = include!(concat!(env!("OUT_DIR"), "/han_stroke.rs"));
+#[cfg(feature = "chinese")]
+pub static HAN_ZHUYIN: HanTable = include!(concat!(env!("OUT_DIR"), "/han_zhuyin.rs"));
+
+// Chinese (pinyin): Han characters in pinyin order, placed with Bopomofo before the Latin script,
+// and the pinyin tones on Latin vowels ordered first, second, third, fourth
+#[cfg(feature = "chinese")]
+#[allow(clippy::unreadable_literal)]
+pub static CHINESE_PINYIN: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_chinese_pinyin.rs"))
+        .with_base(&CLDR_ROOT, &LOW_CLDR)
+        .with_han(&HAN_PINYIN);
+
+// The low weights for Chinese (pinyin), deferring to the table for the letters that start
+// contractions with combining marks (the tones); likewise for the other two orders
+#[cfg(feature = "chinese")]
+pub static LOW_CHINESE_PINYIN: [u32; 183] = defer_low(LOW_CLDR, &CHINESE_PINYIN);
+
+// Chinese (stroke): Han characters by stroke count, then by the first stroke, placed with Bopomofo
+// before the Latin script
+#[cfg(feature = "chinese")]
+#[allow(clippy::unreadable_literal)]
+pub static CHINESE_STROKE: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_chinese_stroke.rs"))
+        .with_base(&CLDR_ROOT, &LOW_CLDR)
+        .with_han(&HAN_STROKE);
+
+#[cfg(feature = "chinese")]
+pub static LOW_CHINESE_STROKE: [u32; 183] = defer_low(LOW_CLDR, &CHINESE_STROKE);
+
+// Chinese (zhuyin): Han characters in the order of their Bopomofo (zhuyin) readings, placed with
+// Bopomofo before the Latin script, and the tone marks before everything else
+#[cfg(feature = "chinese")]
+#[allow(clippy::unreadable_literal)]
+pub static CHINESE_ZHUYIN: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_chinese_zhuyin.rs"))
+        .with_base(&CLDR_ROOT, &LOW_CLDR)
+        .with_han(&HAN_ZHUYIN);
+
+#[cfg(feature = "chinese")]
+pub static LOW_CHINESE_ZHUYIN: [u32; 183] = defer_low(LOW_CLDR, &CHINESE_ZHUYIN);
+
+// The JIS X 0208 order of kanji, following the kana
+#[cfg(feature = "japanese")]
+pub static HAN_JAPANESE: HanTable = include!(concat!(env!("OUT_DIR"), "/han_japanese.rs"));
+
+// Japanese: kana in gojūon order, with the prolonged sound mark and the iteration marks as prefix
+// mappings. Katakana carry an extra tertiary bit (0x20), which sorts them after hiragana; with the
+// hiragana quaternary, it is masked at the tertiary level and compared on its own afterward.
+#[cfg(feature = "japanese")]
+#[allow(clippy::unreadable_literal)]
+pub static JAPANESE: CollationTable = include!(concat!(env!("OUT_DIR"), "/tailoring_japanese.rs"))
+    .with_base(&CLDR_ROOT, &LOW_CLDR)
+    .with_han(&HAN_JAPANESE);
+
+// Korean: the Hangul script sorts before the Latin script, with Hanja given the weights of the jamo
+// of their readings, and a secondary weight (above all of the root's) on the first jamo giving their
+// order among the Hanja of the same reading
+#[cfg(feature = "korean")]
+#[allow(clippy::unreadable_literal)]
+pub static KOREAN: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_korean.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Hindi: the Devanagari script sorts before the Latin script, the candrabindu is a secondary variant
+// of the anusvara, and the visarga follows both
+#[cfg(feature = "hindi")]
+#[allow(clippy::unreadable_literal)]
+pub static HINDI: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_hindi.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Bengali: the Bengali script sorts before the Latin script, and the anusvara, visarga, and
+// candrabindu sort as letters, in that order
+#[cfg(feature = "bengali")]
+#[allow(clippy::unreadable_literal)]
+pub static BENGALI: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_bengali.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Tamil: the Tamil script sorts before the Latin script, with consonants in the traditional order,
+// each with a virama (i.e., without a vowel) before the same consonant with its inherent vowel; and
+// ksha as a letter of its own
+#[cfg(feature = "tamil")]
+#[allow(clippy::unreadable_literal)]
+pub static TAMIL: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_tamil.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Hungarian: cs, dz, dzs, gy, ly, ny, sz, ty, and zs are letters of their own, and a doubled
+// letter written with a single leading consonant (e.g., "ccs" for "cs" + "cs") expands to two of
+// them; ö and ü are separate letters, with ő and ű as their secondary variants
+#[cfg(feature = "hungarian")]
+#[allow(clippy::unreadable_literal)]
+pub static HUNGARIAN: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_hungarian.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for Hungarian, deferring to the table for the letters that start the digraphs
+// and trigraphs (or contractions with combining marks)
+#[cfg(feature = "hungarian")]
+pub static LOW_HUNGARIAN: [u32; 183] = defer_low(LOW_CLDR, &HUNGARIAN);
+
+// Emoji: every emoji, and every emoji sequence (flags, keycaps, and ZWJ sequences, with or without
+// their variation selectors) gets a primary of its own after the last regular one, in the order of
+// the CLDR emoji data, so that emoji are grouped by category; a skin tone modifier keeps its root
+// weights, so that each variant follows its base emoji
+#[cfg(feature = "emoji")]
+#[allow(clippy::unreadable_literal)]
+pub static EMOJI: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_emoji.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The low weights for emoji, deferring to the table for the digits, "#" and "*" (which start
+// keycap sequences), and the copyright and registered signs
+#[cfg(feature = "emoji")]
+pub static LOW_EMOJI: [u32; 183] = defer_low(LOW_CLDR, &EMOJI);
+
+// Greek: the Greek script sorts before the Latin script
+#[cfg(feature = "greek")]
+#[allow(clippy::unreadable_literal)]
+pub static GREEK: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_greek.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// CLDR root collation with Cyrillic-script characters sorted before Latin-script characters (for
+// Russian and Bulgarian, whose letters need no further tailoring)
+#[cfg(any(feature = "russian", feature = "bulgarian"))]
+#[allow(clippy::unreadable_literal)]
+pub static CYRILLIC_SCRIPT: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_cyrillic_script.rs"))
+        .with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Ukrainian: the Cyrillic script sorts before the Latin script, with ґ and ї as letters of their
+// own after г and і
+#[cfg(feature = "ukrainian")]
+#[allow(clippy::unreadable_literal)]
+pub static UKRAINIAN: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_ukrainian.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Serbian (Cyrillic): the Cyrillic script sorts before the Latin script, and й, which Serbian does
+// not use, is a secondary variant of и
+#[cfg(feature = "serbian")]
+#[allow(clippy::unreadable_literal)]
+pub static SERBIAN: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_serbian.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Hebrew: the Hebrew script sorts before the Latin script, and the geresh and gershayim are
+// secondary variants of the apostrophe and the quotation mark
+#[cfg(feature = "hebrew")]
+#[allow(clippy::unreadable_literal)]
+pub static HEBREW: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_hebrew.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Thai: the Thai script sorts before the Latin script, the thanthakhat sorts before the other signs
+// and tone marks, and the repetition and abbreviation marks are punctuation, with variable weights
+#[cfg(feature = "thai")]
+#[allow(clippy::unreadable_literal)]
+pub static THAI: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_thai.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Lao: the Lao script sorts before the Latin script
+#[cfg(feature = "lao")]
+#[allow(clippy::unreadable_literal)]
+pub static LAO: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/tailoring_lao.rs")).with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Earlier versions of the DUCET and CLDR root tables, each stored as an overlay listing only the
+// code points whose weights differ from the current table
+#[cfg(feature = "unicode-14")]
+#[allow(clippy::unreadable_literal)]
+pub static DUCET_UNICODE_14: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/legacy_unicode_14_ducet.rs")).with_base(&DUCET, &LOW_DUCET);
+
+#[cfg(feature = "unicode-14")]
+#[allow(clippy::unreadable_literal)]
+pub static CLDR_UNICODE_14: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/legacy_unicode_14_cldr_root.rs"))
+        .with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// The DUCET of CLDR 43 is that of Unicode 15, so both versions use this table
+#[cfg(any(feature = "unicode-15", feature = "cldr-43"))]
+#[allow(clippy::unreadable_literal)]
+pub static DUCET_UNICODE_15: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/legacy_unicode_15_ducet.rs")).with_base(&DUCET, &LOW_DUCET);
+
+#[cfg(feature = "unicode-15")]
+#[allow(clippy::unreadable_literal)]
+pub static CLDR_UNICODE_15: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/legacy_unicode_15_cldr_root.rs"))
+        .with_base(&CLDR_ROOT, &LOW_CLDR);
+
+#[cfg(feature = "cldr-43")]
+#[allow(clippy::unreadable_literal)]
+pub static CLDR_CLDR_43: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/legacy_cldr_43_cldr_root.rs"))
+        .with_base(&CLDR_ROOT, &LOW_CLDR);
+
+#[cfg(feature = "cldr-44")]
+#[allow(clippy::unreadable_literal)]
+pub static DUCET_CLDR_44: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/legacy_cldr_44_ducet.rs")).with_base(&DUCET, &LOW_DUCET);
+
+#[cfg(feature = "cldr-44")]
+#[allow(clippy::unreadable_literal)]
+pub static CLDR_CLDR_44: CollationTable =
+    include!(concat!(env!("OUT_DIR"), "/legacy_cldr_44_cldr_root.rs"))
+        .with_base(&CLDR_ROOT, &LOW_CLDR);
+
+// Low code point weights for the earlier table versions, where the relative order of the
+// characters involved is unchanged but their primary weights differ (as printed by tools/legacy)
+#[cfg(feature = "unicode-14")]
+#[allow(clippy::unreadable_literal)]
+pub const LOW_DUCET_UNICODE_14: [u32; 183] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 164896, 230432, 295968, 361504, 427040, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 689184, 6980640, 20939808, 30835744, 591594528, 30901280, 30639136,
+    20481056, 21660704, 21726240, 30114848, 112493600, 2524192, 951328, 8619040, 30442528,
+    595067936, 595133472, 595199008, 595264544, 595330080, 595395616, 595461152, 595526688,
+    595592224, 595657760, 4424736, 4031520, 112821280, 112886816, 112952352, 7439392, 30049312,
+    595726368, 597299232, 599003168, 600444960, 602345504, 606212128, 607195168, 609751072,
+    611520544, 613355552, 614993952, LOW_DEFER, 620105760, 621088800, 623644704, 626790432,
+    628166688, 629411872, 633737248, 636555296, 638849056, 641994784, 643174432, 643829792,
+    644616224, 646058016, 21791776, 30508064, 21857312, 82019360, 820256, 81822752, 595723296,
+    597296160, 599000096, 600441888, 602342432, 606209056, 607192096, 609748000, 611517472,
+    613352480, 614990880, LOW_DEFER, 620102688, 621085728, 623641632, 626787360, 628163616,
+    629408800, 633734176, 636552224, 638845984, 641991712, 643171360, 643826720, 644613152,
+    646054944, 21922848, 113083424, 21988384, 113214496, 0, 0, 0, 0, 0, 0, 492576, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 701984, 7046176, 591528992,
+    591660064, 591463456, 591725600, 113148960, 29656096, 82281504, 102794272, 595732512, 21529632,
+    113017888, 0, 102925344, 82084896, 90407968, 112624672, 595208224, 595273760, 81888288,
+    657590304, 29787168,
+];
+
+#[cfg(feature = "unicode-14")]
+#[allow(clippy::unreadable_literal)]
+pub const LOW_CLDR_UNICODE_14: [u32; 183] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 164896, 230432, 295968, 361504, 427040, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 689184, 6980640, 20939808, 30835744, 558433312, 30901280, 30639136,
+    20481056, 21660704, 21726240, 30114848, 112460832, 2524192, 951328, 8619040, 30442528,
+    595592224, 595657760, 595723296, 595788832, 595854368, 595919904, 595985440, 596050976,
+    596116512, 596182048, 4424736, 4031520, 112788512, 112854048, 112919584, 7439392, 30049312,
+    662704160, 664473632, 666177568, 667619360, 669519904, 673386528, 674369568, 676859936,
+    678629408, 680464416, 682102816, LOW_DEFER, 687214624, 688197664, 690753568, 693899296,
+    695275552, 696520736, 700846112, 703664160, 705957920, 709103648, 710283296, 710938656,
+    711725088, 713166880, 21791776, 30508064, 21857312, 81986592, 820256, 81789984, 662701088,
+    664470560, 666174496, 667616288, 669516832, 673383456, 674366496, 676856864, 678626336,
+    680461344, 682099744, LOW_DEFER, 687211552, 688194592, 690750496, 693896224, 695272480,
+    696517664, 700843040, 703661088, 705954848, 709100576, 710280224, 710935584, 711722016,
+    713163808, 21922848, 113050656, 21988384, 113181728, 0, 0, 0, 0, 0, 0, 492576, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 701984, 7046176, 558367776,
+    558498848, 558302240, 558564384, 113116192, 29656096, 82248736, 102761504, 662710304, 21529632,
+    112985120, 0, 102892576, 82052128, 90375200, 112591904, 595732512, 595798048, 81855520,
+    724699168, 29787168,
+];
+
+#[cfg(any(feature = "unicode-15", feature = "cldr-43"))]
+#[allow(clippy::unreadable_literal)]
+pub const LOW_DUCET_UNICODE_15: [u32; 183] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 164896, 230432, 295968, 361504, 427040, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 689184, 6980640, 20939808, 30835744, 591594528, 30901280, 30639136,
+    20481056, 21660704, 21726240, 30114848, 112493600, 2524192, 951328, 8619040, 30442528,
+    595067936, 595133472, 595199008, 595264544, 595330080, 595395616, 595461152, 595526688,
+    595592224, 595657760, 4424736, 4031520, 112821280, 112886816, 112952352, 7439392, 30049312,
+    595726368, 597299232, 599003168, 600444960, 602345504, 606212128, 607195168, 609751072,
+    611520544, 613355552, 614993952, LOW_DEFER, 620105760, 621088800, 623644704, 626790432,
+    628166688, 629411872, 633737248, 636555296, 638849056, 641994784, 643174432, 643829792,
+    644616224, 646058016, 21791776, 30508064, 21857312, 82019360, 820256, 81822752, 595723296,
+    597296160, 599000096, 600441888, 602342432, 606209056, 607192096, 609748000, 611517472,
+    613352480, 614990880, LOW_DEFER, 620102688, 621085728, 623641632, 626787360, 628163616,
+    629408800, 633734176, 636552224, 638845984, 641991712, 643171360, 643826720, 644613152,
+    646054944, 21922848, 113083424, 21988384, 113214496, 0, 0, 0, 0, 0, 0, 492576, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 701984, 7046176, 591528992,
+    591660064, 591463456, 591725600, 113148960, 29656096, 82281504, 102794272, 595732512, 21529632,
+    113017888, 0, 102925344, 82084896, 90407968, 112624672, 595208224, 595273760, 81888288,
+    657590304, 29787168,
+];
+
+#[cfg(feature = "unicode-15")]
+#[allow(clippy::unreadable_literal)]
+pub const LOW_CLDR_UNICODE_15: [u32; 183] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 164896, 230432, 295968, 361504, 427040, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 689184, 6980640, 20939808, 30835744, 558433312, 30901280, 30639136,
+    20481056, 21660704, 21726240, 30114848, 112460832, 2524192, 951328, 8619040, 30442528,
+    595592224, 595657760, 595723296, 595788832, 595854368, 595919904, 595985440, 596050976,
+    596116512, 596182048, 4424736, 4031520, 112788512, 112854048, 112919584, 7439392, 30049312,
+    662704160, 664473632, 666177568, 667619360, 669519904, 673386528, 674369568, 676859936,
+    678629408, 680464416, 682102816, LOW_DEFER, 687214624, 688197664, 690753568, 693899296,
+    695275552, 696520736, 700846112, 703664160, 705957920, 709103648, 710283296, 710938656,
+    711725088, 713166880, 21791776, 30508064, 21857312, 81986592, 820256, 81789984, 662701088,
+    664470560, 666174496, 667616288, 669516832, 673383456, 674366496, 676856864, 678626336,
+    680461344, 682099744, LOW_DEFER, 687211552, 688194592, 690750496, 693896224, 695272480,
+    696517664, 700843040, 703661088, 705954848, 709100576, 710280224, 710935584, 711722016,
+    713163808, 21922848, 113050656, 21988384, 113181728, 0, 0, 0, 0, 0, 0, 492576, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 701984, 7046176, 558367776,
+    558498848, 558302240, 558564384, 113116192, 29656096, 82248736, 102761504, 662710304, 21529632,
+    112985120, 0, 102892576, 82052128, 90375200, 112591904, 595732512, 595798048, 81855520,
+    724699168, 29787168,
+];
+
+#[cfg(feature = "cldr-43")]
+#[allow(clippy::unreadable_literal)]
+pub const LOW_CLDR_CLDR_43: [u32; 183] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 33653792, 33719328, 33784864, 33850400, 33915936, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34178080, 40469536, 54166560, 63538208, 558498848,
+    63603744, 63341600, 53969952, 54363168, 54428704, 62817312, 112526368, 36013088, 34440224,
+    42107936, 63144992, 595592224, 595657760, 595723296, 595788832, 595854368, 595919904,
+    595985440, 596050976, 596116512, 596182048, 37913632, 37520416, 112854048, 112919584,
+    112985120, 40928288, 62751776, 662704160, 664473632, 666177568, 667619360, 669519904,
+    673386528, 674369568, 676859936, 678629408, 680464416, 682102816, LOW_DEFER, 687214624,
+    688197664, 690753568, 693899296, 695275552, 696520736, 700846112, 703664160, 705957920,
+    709103648, 710283296, 710938656, 711725088, 713166880, 54494240, 63210528, 54559776, 82052128,
+    34309152, 81855520, 662701088, 664470560, 666174496, 667616288, 669516832, 673383456,
+    674366496, 676856864, 678626336, 680461344, 682099744, LOW_DEFER, 687211552, 688194592,
+    690750496, 693896224, 695272480, 696517664, 700843040, 703661088, 705954848, 709100576,
+    710280224, 710935584, 711722016, 713163808, 54625312, 113116192, 54690848, 113247264, 0, 0, 0,
+    0, 0, 0, 33981472, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 34190880, 40535072, 558433312, 558564384, 558367776, 558629920, 113181728, 62358560,
+    82314272, 102827040, 662710304, 54232096, 113050656, 0, 102958112, 82117664, 90440736,
+    112657440, 595732512, 595798048, 81921056, 724699168, 62489632,
+];
+
+#[cfg(feature = "cldr-44")]
+#[allow(clippy::unreadable_literal)]
+pub const LOW_DUCET_CLDR_44: [u32; 183] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 33653792, 33719328, 33784864, 33850400, 33915936, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34178080, 40469536, 54166560, 63538208, 591528992,
+    63603744, 63341600, 53969952, 54363168, 54428704, 62817312, 112493600, 36013088, 34440224,
+    42107936, 63144992, 594936864, 595002400, 595067936, 595133472, 595199008, 595264544,
+    595330080, 595395616, 595461152, 595526688, 37913632, 37520416, 112821280, 112886816,
+    112952352, 40928288, 62751776, 595595296, 597299232, 599003168, 600444960, 602345504,
+    606212128, 607195168, 609751072, 611520544, 613355552, 614993952, LOW_DEFER, 620105760,
+    621088800, 623644704, 626790432, 628166688, 629411872, 633737248, 636555296, 638849056,
+    641994784, 643174432, 643829792, 644616224, 646058016, 54494240, 63210528, 54559776, 82019360,
+    34309152, 81822752, 595592224, 597296160, 599000096, 600441888, 602342432, 606209056,
+    607192096, 609748000, 611517472, 613352480, 614990880, LOW_DEFER, 620102688, 621085728,
+    623641632, 626787360, 628163616, 629408800, 633734176, 636552224, 638845984, 641991712,
+    643171360, 643826720, 644613152, 646054944, 54625312, 113083424, 54690848, 113214496, 0, 0, 0,
+    0, 0, 0, 33981472, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 34190880, 40535072, 591463456, 591594528, 591397920, 591660064, 113148960, 62358560,
+    82281504, 102794272, 595601440, 54232096, 113017888, 0, 102925344, 82084896, 90407968,
+    112624672, 595077152, 595142688, 81888288, 657590304, 62489632,
+];
+
+#[cfg(feature = "cldr-44")]
+#[allow(clippy::unreadable_literal)]
+pub const LOW_CLDR_CLDR_44: [u32; 183] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 33653792, 33719328, 33784864, 33850400, 33915936, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34178080, 40469536, 54166560, 63538208, 558433312,
+    63603744, 63341600, 53969952, 54363168, 54428704, 62817312, 112460832, 36013088, 34440224,
+    42107936, 63144992, 595592224, 595657760, 595723296, 595788832, 595854368, 595919904,
+    595985440, 596050976, 596116512, 596182048, 37913632, 37520416, 112788512, 112854048,
+    112919584, 40928288, 62751776, 662704160, 664473632, 666177568, 667619360, 669519904,
+    673386528, 674369568, 676859936, 678629408, 680464416, 682102816, LOW_DEFER, 687214624,
+    688197664, 690753568, 693899296, 695275552, 696520736, 700846112, 703664160, 705957920,
+    709103648, 710283296, 710938656, 711725088, 713166880, 54494240, 63210528, 54559776, 81986592,
+    34309152, 81789984, 662701088, 664470560, 666174496, 667616288, 669516832, 673383456,
+    674366496, 676856864, 678626336, 680461344, 682099744, LOW_DEFER, 687211552, 688194592,
+    690750496, 693896224, 695272480, 696517664, 700843040, 703661088, 705954848, 709100576,
+    710280224, 710935584, 711722016, 713163808, 54625312, 113050656, 54690848, 113181728, 0, 0, 0,
+    0, 0, 0, 33981472, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 34190880, 40535072, 558367776, 558498848, 558302240, 558564384, 113116192, 62358560,
+    82248736, 102761504, 662710304, 54232096, 112985120, 0, 102892576, 82052128, 90375200,
+    112591904, 595732512, 595798048, 81855520, 724699168, 62489632,
+];
+
+// Code points that have either a variable weight, or a primary weight of zero (in the current
+// tables, any of the tailorings, or any of the earlier versions)
+#[allow(clippy::unreadable_literal)]
+pub static VARIABLE: VariableTable = include!(concat!(env!("OUT_DIR"), "/variable.rs"));
@@ -1,8 +1,8 @@
-use crate::cea_match::implicit_a;
+use crate::cea_match::implicit_weights;
 use crate::collator::CollationContext;
 use crate::tables::CollationTable;
 use crate::weights::{primary, variability};
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 pub fn try_initial(ctx: &CollationContext, a_chars: &[u32], b_chars: &[u32]) -> Option<Ordering> {
     let a_first = a_chars[0];
@@ -26,14 +26,19 @@ pub fn try_initial(ctx: &CollationContext, a_chars: &[u32], b_chars: &[u32]) ->
 }
 
 fn can_compare_initial_primaries(a: u32, b: u32, table: &CollationTable) -> bool {
-    a != b && table.max_len(table.entry(a)) == 1 && table.max_len(table.entry(b)) == 1
+    a != b && context_free(a, table) && context_free(b, table)
+}
+
+// A code point whose weights depend on neither the code points after it (as the start of a
+// contraction) nor those before it (through a prefix mapping)
+fn context_free(cp: u32, table: &CollationTable) -> bool {
+    let (table, entry) = table.entry(cp);
+    table.max_len(entry) == 1 && !CollationTable::has_prefix(entry)
 }
 
 fn get_first_primary(val: u32, ctx: &CollationContext) -> u16 {
     // Fast path for low code points
-    if val < 0xB7 && val != 0x6C && val != 0x4C {
-        let weights = ctx.low[val as usize]; // Guaranteed to succeed
-
+    if let Some(weights) = ctx.low_weights(val) {
         if ctx.shifting && variability(weights) {
             return 0;
         }
@@ -42,10 +47,10 @@ fn get_first_primary(val: u32, ctx: &CollationContext) -> u16 {
     }
 
     // Or look in the big table
-    let entry = ctx.table.entry(val);
+    let (table, entry) = ctx.table.entry(val);
 
     if !CollationTable::is_missing(entry) {
-        let row = ctx.table.simple_row(entry);
+        let row = table.simple_row(entry);
         if ctx.shifting && variability(row[0]) {
             return 0;
         }
@@ -54,6 +59,6 @@ fn get_first_primary(val: u32, ctx: &CollationContext) -> u16 {
     }
 
     // If all else failed, calculate implicit weights
-    let first_weights = implicit_a(val);
+    let [first_weights, _] = implicit_weights(ctx.table, val);
     primary(first_weights)
 }
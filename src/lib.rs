@@ -3,11 +3,14 @@
 //! the bare-bones API, this implementation conforms to the standard and allows for the use of the
 //! CLDR root collation order; so it may indeed be useful.
 
+#![no_std]
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
 #![warn(clippy::pedantic, clippy::nursery, clippy::cargo)]
 #![allow(clippy::too_long_first_doc_paragraph)]
 
+extern crate alloc;
+
 mod ascii;
 mod cea;
 mod cea_match;
@@ -24,9 +27,13 @@ mod first_weight;
 mod normalize;
 mod prefix;
 mod sort_key;
+
+mod table_info;
+pub use table_info::{CollationElement, TableError, TableInfo, TableStats};
+
 mod tables;
 
 mod types;
-pub use types::{Locale, Tailoring};
+pub use types::{Locale, TableVersion, Tailoring};
 
 mod weights;
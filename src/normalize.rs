@@ -1,4 +1,5 @@
 use crate::consts::{DECOMP, FCD};
+use alloc::vec::Vec;
 use unicode_canonical_combining_class::get_canonical_combining_class_u32 as get_ccc;
 
 // Jamo-related consts; they live here for now
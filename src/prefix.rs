@@ -20,7 +20,7 @@ pub fn find_byte_prefix(a: &[u8], b: &[u8], ctx: &CollationContext) -> usize {
         return 0;
     };
 
-    if ctx.table.max_len(ctx.table.entry(previous)) != 1 {
+    if ctx.table.max_len_at(previous) != 1 || ends_in_contraction(a, prefix_len, ctx) {
         return 0;
     }
 
@@ -35,22 +35,52 @@ pub fn find_byte_prefix(a: &[u8], b: &[u8], ctx: &CollationContext) -> usize {
         return 0;
     };
 
-    if a_next.is_some_and(|c| get_ccc(c) as u8 != 0)
-        || b_next.is_some_and(|c| get_ccc(c) as u8 != 0)
-    {
+    // The trimmed prefix must not be needed as context: neither for a following combining mark,
+    // nor for a following code point with prefix mappings.
+    let needs_context = |c: u32| get_ccc(c) as u8 != 0 || ctx.table.has_prefix_at(c);
+
+    if a_next.is_some_and(needs_context) || b_next.is_some_and(needs_context) {
         return 0;
     }
 
     prefix_len
 }
 
+// Whether a contraction starting before the last code point of the prefix could reach past its
+// end (e.g., an emoji ZWJ sequence, whose joiner starts nothing). The code point right before the
+// end has already been checked.
+fn ends_in_contraction(bytes: &[u8], end: usize, ctx: &CollationContext) -> bool {
+    let mut start = end;
+
+    for back in 1..ctx.table.longest_contraction() {
+        start -= 1;
+        while start > 0 && is_utf8_continuation(bytes[start]) {
+            start -= 1;
+        }
+
+        if start == 0 {
+            return false;
+        }
+
+        let Some(code_point) = previous_char(bytes, start) else {
+            return true;
+        };
+
+        if ctx.table.max_len_at(code_point) > back + 1 {
+            return true;
+        }
+    }
+
+    false
+}
+
 fn previous_char(bytes: &[u8], end: usize) -> Option<u32> {
     let mut start = end - 1;
     while start > 0 && is_utf8_continuation(bytes[start]) {
         start -= 1;
     }
 
-    std::str::from_utf8(&bytes[start..end])
+    core::str::from_utf8(&bytes[start..end])
         .ok()?
         .chars()
         .next()
@@ -63,7 +93,7 @@ fn next_char(bytes: &[u8], start: usize) -> Result<Option<u32>, ()> {
     };
 
     let len = utf8_char_width(first).ok_or(())?;
-    std::str::from_utf8(bytes.get(start..start + len).ok_or(())?)
+    core::str::from_utf8(bytes.get(start..start + len).ok_or(())?)
         .map_err(|_| ())?
         .chars()
         .next()
@@ -89,7 +119,7 @@ pub fn find_prefix_shifted(a: &[u32], b: &[u32], ctx: &CollationContext) -> usiz
     let prefix_len = a
         .iter()
         .zip(b.iter())
-        .take_while(|(x, y)| x == y && ctx.table.max_len(ctx.table.entry(**x)) == 1)
+        .take_while(|(x, y)| x == y && ctx.table.max_len_at(**x) == 1)
         .count();
 
     if prefix_len > 0 {
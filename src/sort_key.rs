@@ -1,7 +1,10 @@
-use crate::weights::{primary, secondary, tertiary, variability};
-use std::cmp::Ordering;
+use crate::collator::CollationContext;
+use crate::weights::{primary, secondary, swap_case, tertiary, variability};
+use core::cmp::Ordering;
+
+pub fn compare_incremental(a_cea: &[u32], b_cea: &[u32], ctx: &CollationContext) -> Ordering {
+    let shifting = ctx.shifting;
 
-pub fn compare_incremental(a_cea: &[u32], b_cea: &[u32], shifting: bool) -> Ordering {
     if shifting {
         if let Some(o) = compare_primary_shifting(a_cea, b_cea) {
             return o;
@@ -14,7 +17,13 @@ pub fn compare_incremental(a_cea: &[u32], b_cea: &[u32], shifting: bool) -> Orde
         return o;
     }
 
-    if let Some(o) = compare_tertiary(a_cea, b_cea) {
+    if let Some(o) = compare_tertiary(a_cea, b_cea, ctx.upper_first, ctx.hiragana_quaternary) {
+        return o;
+    }
+
+    if ctx.hiragana_quaternary
+        && let Some(o) = compare_kana(a_cea, b_cea)
+    {
         return o;
     }
 
@@ -85,22 +94,57 @@ fn compare_secondary(a_cea: &[u32], b_cea: &[u32]) -> Option<Ordering> {
     compare_nonzero_weights(a_weights, b_weights)
 }
 
-fn compare_tertiary(a_cea: &[u32], b_cea: &[u32]) -> Option<Ordering> {
+fn compare_tertiary(
+    a_cea: &[u32],
+    b_cea: &[u32],
+    upper_first: bool,
+    mask_kana: bool,
+) -> Option<Ordering> {
+    let case_order = |t: u16| {
+        let t = if mask_kana { t & !KATAKANA } else { t };
+        if upper_first { swap_case(t) } else { t }
+    };
+
     let a_weights = a_cea
         .iter()
         .take_while(|x| **x < u32::MAX)
-        .map(|w| tertiary(*w))
+        .map(|w| case_order(tertiary(*w)))
         .filter(|t| *t != 0);
 
     let b_weights = b_cea
         .iter()
         .take_while(|x| **x < u32::MAX)
-        .map(|w| tertiary(*w))
+        .map(|w| case_order(tertiary(*w)))
         .filter(|t| *t != 0);
 
     compare_nonzero_weights(a_weights, b_weights)
 }
 
+// The Japanese table marks katakana with an extra tertiary bit. For the hiragana quaternary, that
+// bit is compared after the rest of the tertiary weight: hiragana (and everything else) before
+// katakana.
+const KATAKANA: u16 = 0x20;
+
+fn compare_kana(a_cea: &[u32], b_cea: &[u32]) -> Option<Ordering> {
+    let kana_order = |t: u16| if t & KATAKANA == 0 { 1 } else { 2 };
+
+    let a_weights = a_cea
+        .iter()
+        .take_while(|x| **x < u32::MAX)
+        .map(|w| tertiary(*w))
+        .filter(|t| *t != 0)
+        .map(kana_order);
+
+    let b_weights = b_cea
+        .iter()
+        .take_while(|x| **x < u32::MAX)
+        .map(|w| tertiary(*w))
+        .filter(|t| *t != 0)
+        .map(kana_order);
+
+    compare_nonzero_weights(a_weights, b_weights)
+}
+
 fn compare_nonzero_weights(
     mut a_weights: impl Iterator<Item = u16>,
     mut b_weights: impl Iterator<Item = u16>,
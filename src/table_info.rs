@@ -0,0 +1,300 @@
+use crate::Tailoring;
+use crate::cea_match::implicit_weights;
+use crate::collator::get_collation_table;
+use crate::tables::{CollationTable, ContractionEdge, LOW_DEFER};
+use crate::weights::{primary, secondary, tertiary, variability};
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::{fmt, slice};
+
+const MAX_CODE_POINT: u32 = 0x10_FFFF;
+const LOW_LIMIT: u32 = 0xB7;
+
+/// This struct provides read-only access to the table of character weights behind a given
+/// tailoring: which code points and contractions it maps, what their weights are, and some overall
+/// statistics. It is meant for debugging sort orders and for checking the built-in tables; collation
+/// itself goes through `Collator`.
+#[derive(Debug, Clone, Copy)]
+pub struct TableInfo {
+    table: &'static CollationTable,
+    low: &'static [u32],
+}
+
+/// A single collation element, i.e., one set of weights, as stored in a table
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct CollationElement {
+    /// The primary weight
+    pub primary: u16,
+    /// The secondary weight
+    pub secondary: u16,
+    /// The tertiary weight
+    pub tertiary: u16,
+    /// Whether the collation element is variable (i.e., subject to shifting)
+    pub variable: bool,
+}
+
+impl CollationElement {
+    const fn from_packed(weights: u32) -> Self {
+        Self {
+            primary: primary(weights),
+            secondary: secondary(weights),
+            tertiary: tertiary(weights),
+            variable: variability(weights),
+        }
+    }
+}
+
+/// Summary statistics for a table of character weights, as returned by `TableInfo::stats`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct TableStats {
+    /// The number of single code points with explicit weights
+    pub code_points: usize,
+    /// The number of contractions (sequences of two or more code points with their own weights)
+    pub contractions: usize,
+    /// The length, in code points, of the longest contraction
+    pub longest_contraction: usize,
+    /// The number of prefix mappings (weights for a code point that apply only after certain
+    /// others)
+    pub prefix_mappings: usize,
+    /// The number of distinct non-zero primary weights across all mappings
+    pub distinct_primaries: usize,
+}
+
+/// This enum describes the ways in which a table of character weights can fail validation. Each
+/// variant carries the code point (or, for a page, the first code point in it) where the problem
+/// was found, or the offending length.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum TableError {
+    /// The page index does not cover the full range of code points; the actual length is given
+    PageIndexLength(usize),
+    /// A page index refers to a page beyond the end of the entries
+    PageOutOfBounds(u32),
+    /// An entry refers to weights or contraction metadata beyond the end of the table, or inherits
+    /// from a base table that does not exist
+    EntryOutOfBounds(u32),
+    /// A contraction refers to edges or weights beyond the end of the table, has unsorted edges, or
+    /// is deeper than its stated maximum length (or longer than the table's longest)
+    ContractionOutOfBounds(u32),
+    /// A prefix mapping refers to weights beyond the end of the table, has an empty prefix, or is
+    /// out of order
+    PrefixOutOfBounds(u32),
+    /// The weights for a code point (or for a contraction or prefix mapping with it) are more
+    /// collation elements than the collator can queue at once
+    RowTooLong(u32),
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PageIndexLength(len) => write!(f, "page index has {len} pages"),
+            Self::PageOutOfBounds(cp) => write!(f, "page for U+{cp:04X} is out of bounds"),
+            Self::EntryOutOfBounds(cp) => write!(f, "entry for U+{cp:04X} is out of bounds"),
+            Self::ContractionOutOfBounds(cp) => {
+                write!(f, "contraction starting with U+{cp:04X} is malformed")
+            }
+            Self::PrefixOutOfBounds(cp) => write!(f, "prefix mapping for U+{cp:04X} is malformed"),
+            Self::RowTooLong(cp) => write!(f, "weights for U+{cp:04X} are too long"),
+        }
+    }
+}
+
+impl core::error::Error for TableError {}
+
+impl TableInfo {
+    /// Access the table of character weights used with the given tailoring
+    #[must_use]
+    pub fn new(tailoring: Tailoring) -> Self {
+        let (table, low) = get_collation_table(tailoring);
+        Self { table, low }
+    }
+
+    /// Iterate over all code points that have explicit weights in the table, in ascending order.
+    /// (Code points without an entry get implicit weights, which `weights` can also report.)
+    pub fn code_points(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..=MAX_CODE_POINT).filter(|&cp| self.row(cp).is_some())
+    }
+
+    /// Iterate over all contractions in the table, i.e., sequences of two or more code points that
+    /// have their own weights. Sequences are ordered by first code point, then depth-first.
+    pub fn contractions(&self) -> impl Iterator<Item = Vec<u32>> + '_ {
+        (0..=MAX_CODE_POINT).flat_map(|cp| {
+            let (table, entry) = self.table.entry(cp);
+
+            let mut found = Vec::new();
+            let mut prefix = vec![cp];
+            collect_contractions(
+                table,
+                table.contraction_edges(entry),
+                &mut prefix,
+                &mut found,
+            );
+            found
+        })
+    }
+
+    /// Iterate over all prefix mappings in the table, as pairs of a prefix and the code point whose
+    /// weights it changes. (E.g., in Japanese, the prolonged sound mark takes the vowel of the kana
+    /// before it.)
+    pub fn prefix_mappings(&self) -> impl Iterator<Item = (Vec<u32>, u32)> + '_ {
+        (0..=MAX_CODE_POINT).flat_map(|cp| {
+            let (table, entry) = self.table.entry(cp);
+            let mappings = if CollationTable::has_prefix(entry) {
+                table.prefix_mappings(cp)
+            } else {
+                &[]
+            };
+
+            mappings.iter().map(move |m| (m.prefix.to_vec(), cp))
+        })
+    }
+
+    /// Get the weights for a code point when it follows the given code points, if one of its
+    /// prefix mappings applies; otherwise `None`.
+    #[must_use]
+    pub fn weights_after(&self, prefix: &[u32], code_point: u32) -> Option<Vec<CollationElement>> {
+        if code_point > MAX_CODE_POINT {
+            return None;
+        }
+
+        let (table, entry) = self.table.entry(code_point);
+        if !CollationTable::has_prefix(entry) {
+            return None;
+        }
+
+        let row = table.get_prefixed(code_point, |back| {
+            prefix.len().checked_sub(back + 1).map(|i| prefix[i])
+        })?;
+
+        Some(
+            row.iter()
+                .map(|&w| CollationElement::from_packed(w))
+                .collect(),
+        )
+    }
+
+    /// Get the weights for a sequence of code points, if the table maps it as a whole. A single
+    /// code point without an explicit entry gets its implicit weights (or its rank, for a Han
+    /// character in a tailoring that orders them); a longer sequence must be a contraction in the
+    /// table. Empty sequences and invalid code points return `None`.
+    #[must_use]
+    pub fn weights(&self, code_points: &[u32]) -> Option<Vec<CollationElement>> {
+        let (&first, tail) = code_points.split_first()?;
+        if code_points.iter().any(|&cp| cp > MAX_CODE_POINT) {
+            return None;
+        }
+
+        let row = if tail.is_empty() {
+            let Some(row) = self.row(first) else {
+                return Some(
+                    implicit_weights(self.table, first)
+                        .iter()
+                        .map(|&w| CollationElement::from_packed(w))
+                        .collect(),
+                );
+            };
+            row
+        } else {
+            let (table, entry) = self.table.entry(first);
+            table.get_seq(entry, tail.iter().copied())?
+        };
+
+        Some(
+            row.iter()
+                .map(|&w| CollationElement::from_packed(w))
+                .collect(),
+        )
+    }
+
+    /// Find all single code points whose first non-zero primary weight is the one given, in
+    /// ascending order. Only explicit entries are considered.
+    #[must_use]
+    pub fn code_points_with_primary(&self, primary_weight: u16) -> Vec<u32> {
+        (0..=MAX_CODE_POINT)
+            .filter(|&cp| self.row(cp).and_then(first_primary) == Some(primary_weight))
+            .collect()
+    }
+
+    /// Report summary statistics for the table
+    #[must_use]
+    pub fn stats(&self) -> TableStats {
+        let mut stats = TableStats::default();
+        let mut primaries = BTreeSet::new();
+
+        for row in (0..=MAX_CODE_POINT).filter_map(|cp| self.row(cp)) {
+            stats.code_points += 1;
+            primaries.extend(nonzero_primaries(row));
+        }
+
+        for seq in self.contractions() {
+            stats.contractions += 1;
+            stats.longest_contraction = stats.longest_contraction.max(seq.len());
+
+            let (table, entry) = self.table.entry(seq[0]);
+            if let Some(row) = table.get_seq(entry, seq[1..].iter().copied()) {
+                primaries.extend(nonzero_primaries(row));
+            }
+        }
+
+        for (prefix, cp) in self.prefix_mappings() {
+            stats.prefix_mappings += 1;
+
+            if let Some(row) = self.weights_after(&prefix, cp) {
+                primaries.extend(row.iter().map(|ce| ce.primary).filter(|&p| p != 0));
+            }
+        }
+
+        stats.distinct_primaries = primaries.len();
+        stats
+    }
+
+    /// Check that the table is internally consistent: every page index, entry, contraction edge and
+    /// weight slice must be in bounds (including in the base table, for a tailoring or an earlier
+    /// version stored as an overlay), and no slice may hold more collation elements than the
+    /// collator can queue at once. The tables are all built into the library, so this is a check
+    /// on its data, e.g., for a test suite after the tables have been regenerated.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableError` describing the first problem found.
+    pub fn validate(&self) -> Result<(), TableError> {
+        self.table.validate()
+    }
+
+    // Code points below the low limit (apart from those deferred to the table, e.g., because they
+    // start contractions) are served from the fast-path array, just as in collation.
+    fn row(&self, cp: u32) -> Option<&'static [u32]> {
+        if cp < LOW_LIMIT && self.low[cp as usize] != LOW_DEFER {
+            return Some(slice::from_ref(&self.low[cp as usize]));
+        }
+
+        let (table, entry) = self.table.entry(cp);
+        (!CollationTable::is_missing(entry)).then(|| table.simple_row(entry))
+    }
+}
+
+fn collect_contractions(
+    table: &CollationTable,
+    edges: &[ContractionEdge],
+    prefix: &mut Vec<u32>,
+    found: &mut Vec<Vec<u32>>,
+) {
+    for edge in edges {
+        prefix.push(edge.code_point);
+
+        if table.edge_row(edge).is_some() {
+            found.push(prefix.clone());
+        }
+
+        collect_contractions(table, table.child_edges(edge), prefix, found);
+        prefix.pop();
+    }
+}
+
+fn first_primary(row: &[u32]) -> Option<u16> {
+    row.iter().map(|&w| primary(w)).find(|&p| p != 0)
+}
+
+fn nonzero_primaries(row: &[u32]) -> impl Iterator<Item = u16> + '_ {
+    row.iter().map(|&w| primary(w)).filter(|&p| p != 0)
+}
@@ -1,20 +1,28 @@
-#[derive(Debug, serde::Deserialize, serde::Serialize)]
+use crate::cea::PENDING_CE_CAPACITY;
+use crate::table_info::TableError;
+
+#[derive(Debug)]
 pub struct CollationTable {
-    pub page_index: Vec<u16>,
-    pub entries: Vec<u64>,
-    pub contraction_meta: Vec<ContractionMeta>,
-    pub edges: Vec<ContractionEdge>,
-    pub weights: Vec<u32>,
+    pub page_index: &'static [u16],
+    pub entries: &'static [u64],
+    pub contraction_meta: &'static [ContractionMeta],
+    pub edges: &'static [ContractionEdge],
+    pub weights: &'static [u32],
+    pub prefixes: &'static [PrefixMapping],
+    pub longest_contraction: u8,
+    pub tailors_ascii: bool,
+    pub base: Option<&'static Self>,
+    pub han: Option<&'static HanTable>,
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Debug)]
 pub struct ContractionMeta {
     pub first_edge: u32,
     pub edge_len: u16,
     pub max_len: u8,
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Debug)]
 pub struct ContractionEdge {
     pub code_point: u32,
     pub next_first_edge: u32,
@@ -23,23 +31,45 @@ pub struct ContractionEdge {
     pub weight_len: u16,
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize)]
+// Weights for a code point that apply only after a given sequence of code points (e.g., the
+// Japanese prolonged sound mark after each kana). Mappings are sorted by code point; for each code
+// point, longer prefixes come first.
+#[derive(Debug)]
+pub struct PrefixMapping {
+    pub code_point: u32,
+    pub prefix: &'static [u32],
+    pub weight_start: u32,
+    pub weight_len: u16,
+}
+
+// The order of the Han characters in a tailoring that sorts them by reading or by stroke count
+// (e.g., Chinese pinyin). A listed character gets the lead primary followed by its rank; anything
+// else keeps its implicit weights. Ranks are stored in pages, like the FCD values, so that the
+// tailoring itself needs no entries for the tens of thousands of characters it orders.
+#[derive(Debug)]
+pub struct HanTable {
+    pub lead: u16,
+    pub page_index: &'static [u16],
+    pub pages: &'static [u16],
+}
+
+#[derive(Debug)]
 pub struct VariableTable {
-    pub page_index: Vec<u16>,
-    pub pages: Vec<u64>,
+    pub page_index: &'static [u16],
+    pub pages: &'static [u64],
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Debug)]
 pub struct FcdTable {
-    pub page_index: Vec<u16>,
-    pub pages: Vec<u16>,
+    pub page_index: &'static [u16],
+    pub pages: &'static [u16],
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Debug)]
 pub struct DecompTable {
-    pub page_index: Vec<u16>,
-    pub entries: Vec<u64>,
-    pub values: Vec<u32>,
+    pub page_index: &'static [u16],
+    pub entries: &'static [u64],
+    pub values: &'static [u32],
 }
 
 impl DecompTable {
@@ -73,6 +103,34 @@ impl FcdTable {
     }
 }
 
+impl HanTable {
+    pub fn get(&self, code_point: u32) -> Option<u16> {
+        let page = self.page_index[(code_point >> 8) as usize];
+        if page == EMPTY_PAGE {
+            return None;
+        }
+
+        let index = usize::from(page) * 256 + (code_point & 0xFF) as usize;
+        let rank = self.pages[index];
+        (rank != 0).then_some(rank)
+    }
+
+    fn validate(&self) -> Result<(), TableError> {
+        if self.page_index.len() != PAGE_COUNT {
+            return Err(TableError::PageIndexLength(self.page_index.len()));
+        }
+
+        for (block, &page) in self.page_index.iter().enumerate() {
+            if page != EMPTY_PAGE && (usize::from(page) + 1) * 256 > self.pages.len() {
+                #[allow(clippy::cast_possible_truncation)]
+                return Err(TableError::PageOutOfBounds((block as u32) << 8));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl VariableTable {
     pub fn contains(&self, code_point: u32) -> bool {
         let page = self.page_index[(code_point >> 8) as usize];
@@ -89,9 +147,109 @@ impl VariableTable {
 }
 
 impl CollationTable {
-    pub fn entry(&self, code_point: u32) -> u64 {
-        let page = self.page_index[(code_point >> 8) as usize] as usize;
-        self.entries[(page << 8) + (code_point & 0xFF) as usize]
+    // (This and `with_han` are unused when the locale features that need them are all disabled.)
+    #[allow(dead_code)]
+    pub const fn with_base(self, base: &'static Self, base_low: &[u32; 183]) -> Self {
+        Self {
+            tailors_ascii: self.changes_ascii_order(base, base_low),
+            base: Some(base),
+            ..self
+        }
+    }
+
+    // Whether an overlay table sorts ASCII letters and digits differently from its base: by giving
+    // one of them new weights or a prefix mapping, or by letting it start a contraction with another
+    // ASCII character. Contractions with anything else are fine, since the ASCII fast path stops
+    // before a non-ASCII character.
+    const fn changes_ascii_order(&self, base: &Self, base_low: &[u32; 183]) -> bool {
+        let page = self.page_index[0];
+        if page == EMPTY_PAGE {
+            return false;
+        }
+
+        let mut cp = b'0';
+        while cp <= b'z' {
+            let entry = self.entries[((page as usize) << 8) + cp as usize];
+
+            if cp.is_ascii_alphanumeric() && entry_tag(entry) != ENTRY_INHERIT {
+                let base_weights = if base_low[cp as usize] == LOW_DEFER {
+                    let (base, base_entry) = base.entry(cp as u32);
+                    base.single_weights(base_entry)
+                } else {
+                    Some(base_low[cp as usize])
+                };
+
+                if Self::has_prefix(entry)
+                    || !Self::is_contraction(entry)
+                    || !matches!(
+                        (self.single_weights(entry), base_weights),
+                        (Some(a), Some(b)) if a == b
+                    )
+                    || self.continues_with_ascii(entry)
+                {
+                    return true;
+                }
+            }
+
+            cp += 1;
+        }
+
+        false
+    }
+
+    // The weights for an entry's own code point, if they make up a single collation element
+    const fn single_weights(&self, entry: u64) -> Option<u32> {
+        if Self::is_missing(entry) || entry_len(entry) != 1 {
+            return None;
+        }
+
+        Some(self.weights[entry_start(entry) as usize])
+    }
+
+    const fn continues_with_ascii(&self, entry: u64) -> bool {
+        let meta = &self.contraction_meta[entry_meta_index(entry)];
+
+        let mut i = meta.first_edge as usize;
+        while i < meta.first_edge as usize + meta.edge_len as usize {
+            if self.edges[i].code_point < 0x80 {
+                return true;
+            }
+            i += 1;
+        }
+
+        false
+    }
+
+    #[allow(dead_code)]
+    pub const fn with_han(self, han: &'static HanTable) -> Self {
+        Self {
+            han: Some(han),
+            ..self
+        }
+    }
+
+    // An overlay table only lists the code points that differ from its base table; anything else
+    // is looked up in the base. The returned table is the one whose weights the entry refers to.
+    pub const fn entry(&self, code_point: u32) -> (&Self, u64) {
+        let page = self.page_index[(code_point >> 8) as usize];
+
+        let Some(base) = self.base else {
+            return (
+                self,
+                self.entries[((page as usize) << 8) + (code_point & 0xFF) as usize],
+            );
+        };
+
+        if page == EMPTY_PAGE {
+            return base.entry(code_point);
+        }
+
+        let entry = self.entries[((page as usize) << 8) + (code_point & 0xFF) as usize];
+        if entry_tag(entry) == ENTRY_INHERIT {
+            return base.entry(code_point);
+        }
+
+        (self, entry)
     }
 
     pub const fn is_missing(entry: u64) -> bool {
@@ -102,6 +260,42 @@ impl CollationTable {
         entry_tag(entry) == ENTRY_CONTRACTION
     }
 
+    pub const fn has_prefix(entry: u64) -> bool {
+        entry & ENTRY_PREFIX != 0
+    }
+
+    pub const fn has_prefix_at(&self, code_point: u32) -> bool {
+        let (_, entry) = self.entry(code_point);
+        Self::has_prefix(entry)
+    }
+
+    // Weights for a code point given the code points before it, if one of its prefix mappings
+    // matches. `previous(n)` returns the code point n places back (0 being the one immediately
+    // before); the longest matching prefix wins.
+    pub fn get_prefixed(
+        &self,
+        code_point: u32,
+        mut previous: impl FnMut(usize) -> Option<u32>,
+    ) -> Option<&[u32]> {
+        let mapping = self.prefix_mappings(code_point).iter().find(|mapping| {
+            (0..)
+                .zip(mapping.prefix.iter().rev())
+                .all(|(back, &cp)| previous(back) == Some(cp))
+        })?;
+
+        Some(self.weights_slice(mapping.weight_start, mapping.weight_len))
+    }
+
+    pub fn prefix_mappings(&self, code_point: u32) -> &[PrefixMapping] {
+        let start = self.prefixes.partition_point(|m| m.code_point < code_point);
+        let len = self.prefixes[start..]
+            .iter()
+            .take_while(|m| m.code_point == code_point)
+            .count();
+
+        &self.prefixes[start..start + len]
+    }
+
     pub fn max_len(&self, entry: u64) -> usize {
         if Self::is_contraction(entry) {
             usize::from(self.contraction_meta(entry).max_len)
@@ -110,50 +304,183 @@ impl CollationTable {
         }
     }
 
+    pub fn max_len_at(&self, code_point: u32) -> usize {
+        let (table, entry) = self.entry(code_point);
+        table.max_len(entry)
+    }
+
+    // The length, in code points, of the longest contraction in the table or its base
+    pub fn longest_contraction(&self) -> usize {
+        let base = self.base.map_or(1, Self::longest_contraction);
+        usize::from(self.longest_contraction).max(base)
+    }
+
     pub fn simple_row(&self, entry: u64) -> &[u32] {
         self.weights_slice(entry_start(entry), entry_len(entry))
     }
 
-    pub fn get2(&self, entry: u64, b: u32) -> Option<&[u32]> {
-        if !Self::is_contraction(entry) {
-            return None;
+    // Weights for a complete mapping: the code point whose entry is given, followed by the
+    // (possibly empty) tail of a contraction. Contractions may be of any length; each further code
+    // point is one step down the trie of edges.
+    pub fn get_seq(&self, entry: u64, tail: impl IntoIterator<Item = u32>) -> Option<&[u32]> {
+        let mut tail = tail.into_iter();
+
+        let Some(first) = tail.next() else {
+            return (!Self::is_missing(entry)).then(|| self.simple_row(entry));
+        };
+
+        let mut edge = find_edge_in(self.contraction_edges(entry), first)?;
+        for cp in tail {
+            edge = find_edge_in(self.child_edges(edge), cp)?;
         }
 
-        let meta = self.contraction_meta(entry);
-        let edge = self.find_edge(meta.first_edge, meta.edge_len, b)?;
         self.edge_row(edge)
     }
 
-    pub fn get3(&self, entry: u64, b: u32, c: u32) -> Option<&[u32]> {
+    pub fn contraction_edges(&self, entry: u64) -> &[ContractionEdge] {
         if !Self::is_contraction(entry) {
-            return None;
+            return &[];
         }
 
         let meta = self.contraction_meta(entry);
-        let edge = self.find_edge(meta.first_edge, meta.edge_len, b)?;
-        let edge = self.find_edge(edge.next_first_edge, edge.next_edge_len, c)?;
-        self.edge_row(edge)
+        self.edge_range(meta.first_edge, meta.edge_len)
     }
 
-    fn contraction_meta(&self, entry: u64) -> &ContractionMeta {
-        &self.contraction_meta[entry_meta_index(entry)]
+    pub fn child_edges(&self, edge: &ContractionEdge) -> &[ContractionEdge] {
+        self.edge_range(edge.next_first_edge, edge.next_edge_len)
     }
 
-    fn find_edge(&self, first_edge: u32, edge_len: u16, cp: u32) -> Option<&ContractionEdge> {
-        let start = first_edge as usize;
-        let range = &self.edges[start..start + edge_len as usize];
+    pub fn validate(&self) -> Result<(), TableError> {
+        if self.page_index.len() != PAGE_COUNT {
+            return Err(TableError::PageIndexLength(self.page_index.len()));
+        }
+
+        for (block, &page) in self.page_index.iter().enumerate() {
+            #[allow(clippy::cast_possible_truncation)]
+            let first_code_point = (block as u32) << 8;
+
+            if page == EMPTY_PAGE && self.base.is_some() {
+                continue;
+            }
+
+            let page_start = usize::from(page) << 8;
+            let Some(entries) = self.entries.get(page_start..page_start + 256) else {
+                return Err(TableError::PageOutOfBounds(first_code_point));
+            };
+
+            for (code_point, &entry) in (first_code_point..).zip(entries) {
+                self.validate_entry(code_point, entry)?;
+            }
+        }
+
+        self.validate_prefixes()?;
+        self.han.map_or(Ok(()), HanTable::validate)?;
+        self.base.map_or(Ok(()), Self::validate)
+    }
+
+    fn validate_prefixes(&self) -> Result<(), TableError> {
+        let sorted = self
+            .prefixes
+            .is_sorted_by(|a, b| a.code_point <= b.code_point);
+
+        for mapping in self.prefixes {
+            if !sorted
+                || mapping.prefix.is_empty()
+                || !self.weights_in_bounds(mapping.weight_start, mapping.weight_len)
+            {
+                return Err(TableError::PrefixOutOfBounds(mapping.code_point));
+            }
+
+            if usize::from(mapping.weight_len) > PENDING_CE_CAPACITY {
+                return Err(TableError::RowTooLong(mapping.code_point));
+            }
+        }
+
+        Ok(())
+    }
 
-        if edge_len <= 4 {
-            return range.iter().find(|edge| edge.code_point == cp);
+    fn validate_entry(&self, code_point: u32, entry: u64) -> Result<(), TableError> {
+        let in_bounds = match entry_tag(entry) {
+            ENTRY_MISSING => true,
+            ENTRY_INHERIT => self.base.is_some(),
+            tag => {
+                self.weights_in_bounds(entry_start(entry), entry_len(entry))
+                    && (tag != ENTRY_CONTRACTION
+                        || entry_meta_index(entry) < self.contraction_meta.len())
+            }
+        };
+
+        if !in_bounds {
+            return Err(TableError::EntryOutOfBounds(code_point));
         }
 
-        let index = range
-            .binary_search_by_key(&cp, |edge| edge.code_point)
-            .ok()?;
-        Some(&range[index])
+        if !matches!(entry_tag(entry), ENTRY_MISSING | ENTRY_INHERIT)
+            && usize::from(entry_len(entry)) > PENDING_CE_CAPACITY
+        {
+            return Err(TableError::RowTooLong(code_point));
+        }
+
+        if Self::is_contraction(entry) {
+            let meta = self.contraction_meta(entry);
+            let max_depth = usize::from(meta.max_len).saturating_sub(1);
+
+            if meta.max_len > self.longest_contraction
+                || !self.edges_valid(meta.first_edge, meta.edge_len, max_depth)
+            {
+                return Err(TableError::ContractionOutOfBounds(code_point));
+            }
+
+            if !self.edge_rows_fit(meta.first_edge, meta.edge_len) {
+                return Err(TableError::RowTooLong(code_point));
+            }
+        }
+
+        Ok(())
+    }
+
+    // The depth limit (from the contraction's maximum length) also guards against cycles
+    fn edges_valid(&self, first_edge: u32, edge_len: u16, max_depth: usize) -> bool {
+        if edge_len == 0 {
+            return true;
+        }
+
+        let start = first_edge as usize;
+        let Some(range) = self.edges.get(start..start + usize::from(edge_len)) else {
+            return false;
+        };
+
+        max_depth > 0
+            && range.is_sorted_by(|a, b| a.code_point < b.code_point)
+            && range.iter().all(|edge| {
+                (edge.weight_len == 0 || self.weights_in_bounds(edge.weight_start, edge.weight_len))
+                    && self.edges_valid(edge.next_first_edge, edge.next_edge_len, max_depth - 1)
+            })
+    }
+
+    // Whether the rows of a contraction (with its edges already checked) fit the collator's queue
+    fn edge_rows_fit(&self, first_edge: u32, edge_len: u16) -> bool {
+        self.edge_range(first_edge, edge_len).iter().all(|edge| {
+            usize::from(edge.weight_len) <= PENDING_CE_CAPACITY
+                && self.edge_rows_fit(edge.next_first_edge, edge.next_edge_len)
+        })
+    }
+
+    fn weights_in_bounds(&self, start: u32, len: u16) -> bool {
+        (start as usize)
+            .checked_add(usize::from(len))
+            .is_some_and(|end| end <= self.weights.len())
     }
 
-    fn edge_row(&self, edge: &ContractionEdge) -> Option<&[u32]> {
+    fn contraction_meta(&self, entry: u64) -> &ContractionMeta {
+        &self.contraction_meta[entry_meta_index(entry)]
+    }
+
+    fn edge_range(&self, first_edge: u32, edge_len: u16) -> &[ContractionEdge] {
+        let start = first_edge as usize;
+        &self.edges[start..start + usize::from(edge_len)]
+    }
+
+    pub fn edge_row(&self, edge: &ContractionEdge) -> Option<&[u32]> {
         if edge.weight_len == 0 {
             return None;
         }
@@ -167,9 +494,42 @@ impl CollationTable {
     }
 }
 
+// Marks a slot in a low weights array whose code point has to be looked up in the main table (e.g.,
+// because it starts a contraction)
+pub const LOW_DEFER: u32 = u32::MAX;
+
+// Derive the low weights array for a tailoring from that of its base, deferring to the table for
+// any low code point that the tailoring maps explicitly
+#[allow(dead_code)]
+pub const fn defer_low(mut low: [u32; 183], table: &CollationTable) -> [u32; 183] {
+    let mut cp = 0;
+    while cp < 183 {
+        let (_, entry) = table.entry(cp);
+        if !CollationTable::is_missing(entry) {
+            low[cp as usize] = LOW_DEFER;
+        }
+        cp += 1;
+    }
+    low
+}
+
 const ENTRY_MISSING: u8 = 0;
 const ENTRY_CONTRACTION: u8 = 2;
+const ENTRY_INHERIT: u8 = 3;
+const ENTRY_PREFIX: u64 = 1 << 63;
 const EMPTY_PAGE: u16 = u16::MAX;
+const PAGE_COUNT: usize = 0x1100;
+
+fn find_edge_in(range: &[ContractionEdge], cp: u32) -> Option<&ContractionEdge> {
+    if range.len() <= 4 {
+        return range.iter().find(|edge| edge.code_point == cp);
+    }
+
+    let index = range
+        .binary_search_by_key(&cp, |edge| edge.code_point)
+        .ok()?;
+    Some(&range[index])
+}
 
 const fn entry_tag(entry: u64) -> u8 {
     (entry & 0b11) as u8
@@ -184,7 +544,7 @@ const fn entry_start(entry: u64) -> u32 {
 }
 
 const fn entry_meta_index(entry: u64) -> usize {
-    (entry >> 50) as usize
+    ((entry >> 50) & 0x1FFF) as usize
 }
 
 const fn decomp_len(entry: u64) -> u16 {
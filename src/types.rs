@@ -8,6 +8,15 @@ pub enum Tailoring {
     Cldr(Locale),
     /// The default table for the Unicode Collation Algorithm
     Ducet,
+    /// The CLDR root collation order as it stood in an earlier data version. This is meant for
+    /// keeping sort orders stable (e.g., in a persisted index) across upgrades of the library.
+    /// There is no choice of locale here: the earlier versions are of the root order only, and
+    /// `Locale` tailorings always build on the current version. This variant is always defined,
+    /// but it can only be constructed when one of the versions is enabled (see `TableVersion`).
+    CldrLegacy(TableVersion),
+    /// The default table for the Unicode Collation Algorithm, as it stood in an earlier data
+    /// version (likewise only constructible when one of the versions is enabled)
+    DucetLegacy(TableVersion),
 }
 
 impl Default for Tailoring {
@@ -17,9 +26,21 @@ impl Default for Tailoring {
 }
 
 /// This enum provides for a choice of which locale to use with the CLDR table of character weights.
-/// The default, `Root`, represents the CLDR root collation order. At the moment, there are only two
-/// other choices: `ArabicScript` and `ArabicInterleaved`. But the list should grow over time.
+/// The default, `Root`, represents the CLDR root collation order. The other choices are tailorings
+/// of it for particular languages or scripts; the list should continue to grow over time.
+///
+/// Apart from `ArabicScript` and `ArabicInterleaved`, the tailorings follow the rules of earlier
+/// CLDR versions (22.1 to 30), as transcribed in Perl's `Unicode::Collate`; characters added since
+/// Unicode 13 are not tailored. (See the README for details.)
+///
+/// Apart from `Root`, `German`, `ArabicScript`, and `ArabicInterleaved`, each locale is available
+/// only when the Cargo feature of its language is enabled (e.g., `swedish`; or `chinese` for all
+/// three Chinese orders, and `norwegian` for both forms of Norwegian). The `locales` feature,
+/// which is on by default, enables all of them; turning off the default features leaves their
+/// tables out of the binary. Since enabling a feature adds a variant, the enum is marked
+/// `non_exhaustive`.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+#[non_exhaustive]
 pub enum Locale {
     /// This locale defines a tailoring in which the Arabic script sorts before the Latin script. No
     /// more granular adjustments have been made.
@@ -27,7 +48,198 @@ pub enum Locale {
     /// This locale defines a tailoring in which Arabic-script characters are interleaved with
     /// Latin-script characters, so that _alif_ sorts between A and B, _bā’_ between B and C, etc.
     ArabicInterleaved,
+    /// Standard German. CLDR does not tailor the root collation order for German, so this is
+    /// equivalent to `Root`; it is provided for clarity, alongside `GermanPhonebook`.
+    German,
+    /// German phonebook order (CLDR `de-u-co-phonebk`), in which "ä", "ö" and "ü" sort as "ae",
+    /// "oe" and "ue", respectively. They remain distinct from those two-letter sequences at the
+    /// secondary level, so that "Mueller" sorts before "Müller".
+    #[cfg(feature = "german-phonebook")]
+    GermanPhonebook,
+    /// Swedish. The letters "å," "ä," and "ö" sort as separate letters after "z"; "ü" sorts
+    /// with "y"; and "v" and "w" remain distinct, as in current CLDR.
+    #[cfg(feature = "swedish")]
+    Swedish,
+    /// Finnish. As in Swedish, "å," "ä," and "ö" sort as separate letters after "z," and "ü"
+    /// sorts with "y"; "v" and "w" remain distinct.
+    #[cfg(feature = "finnish")]
+    Finnish,
+    /// Danish. The letters "æ," "ø," and "å" sort as separate letters after "z," and "aa"
+    /// sorts as "å." Uppercase letters sort before lowercase ones.
+    #[cfg(feature = "danish")]
+    Danish,
+    /// Norwegian Bokmål. As in Danish, "æ," "ø," and "å" sort as separate letters after "z," and
+    /// "aa" sorts as "å"; but lowercase letters sort before uppercase ones, as usual.
+    #[cfg(feature = "norwegian")]
+    NorwegianBokmal,
+    /// Norwegian Nynorsk, which sorts the same way as `NorwegianBokmal`
+    #[cfg(feature = "norwegian")]
+    NorwegianNynorsk,
+    /// Spanish. The letter "ñ" sorts as a separate letter after "n."
+    #[cfg(feature = "spanish")]
+    Spanish,
+    /// Traditional Spanish. As in `Spanish`, "ñ" sorts after "n"; and "ch" and "ll" sort as
+    /// single letters after "c" and "l," respectively.
+    #[cfg(feature = "spanish")]
+    SpanishTraditional,
+    /// Turkish. The letters "ç," "ğ," "ı," "ö," "ş," and "ü" sort as separate letters after "c,"
+    /// "g," "h," "o," "s," and "u," respectively; and the dotless "ı" sorts before "i." Case
+    /// pairs follow Turkish usage: "I" is the capital of "ı," and "İ" of "i."
+    #[cfg(feature = "turkish")]
+    Turkish,
+    /// Azerbaijani. As in `Turkish`, with the further Azerbaijani letter order: "q" after "k,"
+    /// "x" after "h," and "w" after "z."
+    #[cfg(feature = "azerbaijani")]
+    Azerbaijani,
+    /// Polish. The letters "ą," "ć," "ę," "ł," "ń," "ó," "ś," "ź," and "ż" sort as separate
+    /// letters, each after its base letter (with "ź" before "ż").
+    #[cfg(feature = "polish")]
+    Polish,
+    /// Czech. The letters "č," "ř," "š," and "ž" sort as separate letters after "c," "r," "s,"
+    /// and "z"; and "ch" sorts as a single letter after "h."
+    #[cfg(feature = "czech")]
+    Czech,
+    /// Slovak. As in `Czech`, "č," "ř," "š," and "ž" are separate letters and "ch" sorts after
+    /// "h"; in addition, "ä" and "ô" sort after "a" and "o."
+    #[cfg(feature = "slovak")]
+    Slovak,
+    /// Persian (CLDR `fa`). As with `ArabicScript`, Arabic-script letters sort before the Latin
+    /// script. In addition, the forms of _alif_ and _hamza_ follow the Persian order, and the
+    /// Arabic letters ك and ي differ from the Persian ک and ی only at the secondary level.
+    #[cfg(feature = "persian")]
+    Persian,
+    /// Urdu. Arabic-script letters sort before the Latin script, in the Urdu alphabetical order
+    /// (e.g., "ٹ" after "ت," "ڈ" after "د," and "ں" after "ن"). Aspirated consonants written
+    /// with "ھ" sort as letters of their own, after the corresponding plain consonant.
+    #[cfg(feature = "urdu")]
+    Urdu,
+    /// Pashto. Arabic-script letters sort before the Latin script, in the Pashto alphabetical order
+    /// (e.g., "ټ" after "ت," "ځ" and "چ" after "ج," and "ښ" after "ش").
+    #[cfg(feature = "pashto")]
+    Pashto,
+    /// Lithuanian. "y" sorts as a secondary variant of "i"; "č," "š," and "ž" sort as separate
+    /// letters after "c," "s," and "z"; and the letters with ogonek, dot above, or macron sort as
+    /// secondary variants of their base letters. An explicit dot above on "i" before a grave,
+    /// acute, or tilde accent (as in "i̇̀") is ignored.
+    #[cfg(feature = "lithuanian")]
+    Lithuanian,
+    /// Vietnamese. "ă," "â," "đ," "ê," "ô," "ơ," and "ư" sort as separate letters after their
+    /// base letters, and the tone marks are ordered at the secondary level: unmarked, grave, hook
+    /// above, tilde, acute, dot below.
+    #[cfg(feature = "vietnamese")]
+    Vietnamese,
+    /// Chinese, sorted by pinyin. Han characters sort by their (most common) reading, before the
+    /// Latin script; those without a listed reading follow all other characters, in code point
+    /// order.
+    #[cfg(feature = "chinese")]
+    ChinesePinyin,
+    /// Chinese, sorted by stroke count. Han characters sort by their number of strokes (then by the
+    /// first stroke), before the Latin script.
+    #[cfg(feature = "chinese")]
+    ChineseStroke,
+    /// Chinese, sorted by zhuyin (Bopomofo). Han characters sort by their reading in Bopomofo
+    /// order, before the Latin script.
+    #[cfg(feature = "chinese")]
+    ChineseZhuyin,
+    /// Japanese. Kana sort in gojūon order before kanji, which follow the JIS X 0208 order (by
+    /// reading); voiced and semi-voiced kana differ at the secondary level, and small kana,
+    /// hiragana and katakana at the tertiary level. The prolonged sound mark and the iteration
+    /// marks take the vowel or the kana before them.
+    #[cfg(feature = "japanese")]
+    Japanese,
+    /// Japanese, with hiragana and katakana distinguished only after all other tertiary
+    /// differences, at an additional level (hiragana first).
+    #[cfg(feature = "japanese")]
+    JapaneseHiraganaQuaternary,
+    /// Korean. The Hangul script sorts before the Latin script, and Hanja sort with their Hangul
+    /// readings, each after the syllable it is read as (and before the next one), as in CLDR's
+    /// search order. Han characters without a reading keep their implicit weights, after Latin.
+    #[cfg(feature = "korean")]
+    Korean,
+    /// Hindi. The Devanagari script sorts before the Latin script. The candrabindu ("ँ") sorts
+    /// after the anusvara ("ं"), as a secondary variant of it, and the visarga ("ः") after both.
+    #[cfg(feature = "hindi")]
+    Hindi,
+    /// Bengali. The Bengali script sorts before the Latin script. The anusvara ("ং"), visarga
+    /// ("ঃ"), and candrabindu ("ঁ") sort as letters of their own, in that order (the candrabindu
+    /// coming first in the root order).
+    #[cfg(feature = "bengali")]
+    Bengali,
+    /// Tamil. The Tamil script sorts before the Latin script. Consonants follow the traditional
+    /// order, with the Grantha letters last; a consonant with a virama ("க்") sorts before the same
+    /// consonant with its inherent vowel ("க"); and "க்ஷ" sorts as a letter of its own, after "ஹ."
+    #[cfg(feature = "tamil")]
+    Tamil,
+    /// Hungarian. "cs," "dz," "dzs," "gy," "ly," "ny," "sz," "ty," and "zs" sort as letters of
+    /// their own, and a long consonant written with a doubled first letter (e.g., "ccs" or "ddzs")
+    /// sorts as two of them. "ö" and "ü" sort as separate letters after "o" and "u," with "ő"
+    /// and "ű" as secondary variants of them.
+    #[cfg(feature = "hungarian")]
+    Hungarian,
+    /// Emoji, grouped by category in the order of the CLDR emoji data (smileys, people,
+    /// animals, food, and so on). ZWJ sequences, flags, and keycaps sort as single emoji, and
+    /// a skin tone variant follows its base emoji. Other characters keep their root order.
+    #[cfg(feature = "emoji")]
+    Emoji,
+    /// Greek. The Greek script sorts before the Latin script (and the Coptic script, which keeps
+    /// its place after Greek in the root order).
+    #[cfg(feature = "greek")]
+    Greek,
+    /// Russian. The Cyrillic script sorts before the Latin script; the letters themselves keep
+    /// their root order.
+    #[cfg(feature = "russian")]
+    Russian,
+    /// Bulgarian. As with `Russian`, the Cyrillic script sorts before the Latin script, with the
+    /// letters in their root order.
+    #[cfg(feature = "bulgarian")]
+    Bulgarian,
+    /// Ukrainian. The Cyrillic script sorts before the Latin script, and "ґ" and "ї" sort as
+    /// separate letters after "г" and "і" (rather than as variants of them, as in the root order).
+    #[cfg(feature = "ukrainian")]
+    Ukrainian,
+    /// Serbian (in the Cyrillic script). The Cyrillic script sorts before the Latin script, and
+    /// "й," which is not a Serbian letter, sorts as "и" with a secondary difference.
+    #[cfg(feature = "serbian")]
+    Serbian,
+    /// Hebrew. The Hebrew script sorts before the Latin script, and the geresh ("׳") and
+    /// gershayim ("״") differ from the apostrophe and the quotation mark only at the secondary
+    /// level (sorting just before them). Niqqud are secondary differences, as in the root order.
+    #[cfg(feature = "hebrew")]
+    Hebrew,
+    /// Thai. The Thai script sorts before the Latin script, and, as in the root order, a prevowel
+    /// ("เ," "แ," "โ," "ใ," or "ไ") sorts after the consonant that follows it. In addition, the
+    /// thanthakhat ("์") sorts before the other signs and tone marks at the secondary level, and the
+    /// repetition and abbreviation marks ("ๆ" and "ฯ") are punctuation, with variable weights.
+    #[cfg(feature = "thai")]
+    Thai,
+    /// Lao. The Lao script sorts before the Latin script; the letters themselves keep their root
+    /// order, in which a prevowel sorts after the consonant that follows it.
+    #[cfg(feature = "lao")]
+    Lao,
     /// The CLDR root collation order
     #[default]
     Root,
 }
+
+/// This enum provides for a choice among earlier versions of the tables of character weights, for
+/// use with `Tailoring::CldrLegacy` or `Tailoring::DucetLegacy`. Each version is available only
+/// when the feature of the same name is enabled; with none of them enabled, the enum has no
+/// variants at all. Since enabling a feature adds a variant, the enum is marked `non_exhaustive`.
+/// (The current version, Unicode 16 / CLDR 46.1, is always used with `Tailoring::Cldr` and
+/// `Tailoring::Ducet`.)
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum TableVersion {
+    /// Unicode 14 (feature `unicode-14`)
+    #[cfg(feature = "unicode-14")]
+    Unicode14,
+    /// Unicode 15 (feature `unicode-15`)
+    #[cfg(feature = "unicode-15")]
+    Unicode15,
+    /// CLDR 43, based on Unicode 15 (feature `cldr-43`)
+    #[cfg(feature = "cldr-43")]
+    Cldr43,
+    /// CLDR 44, based on Unicode 15.1 (feature `cldr-44`)
+    #[cfg(feature = "cldr-44")]
+    Cldr44,
+}
@@ -31,6 +31,18 @@ pub const fn shift_weights(weights: u32, last_variable: &mut bool) -> u32 {
     }
 }
 
+// Exchange the tertiary weights of lowercase and uppercase variants (e.g., 0x02 for "a" and 0x08
+// for "A"), for tailorings that sort uppercase first
+pub const fn swap_case(tertiary: u16) -> u16 {
+    match tertiary {
+        0x02..=0x06 => tertiary + 6,
+        0x08..=0x0C => tertiary - 6,
+        0x1C => 0x1D,
+        0x1D => 0x1C,
+        _ => tertiary,
+    }
+}
+
 pub const fn tertiary(weights: u32) -> u16 {
     (((weights & 0xFFFF) >> 9) & 0b11_1111) as u16
 }
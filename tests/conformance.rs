@@ -1,3 +1,10 @@
+#[cfg(any(
+    feature = "unicode-14",
+    feature = "unicode-15",
+    feature = "cldr-43",
+    feature = "cldr-44"
+))]
+use feruca::TableVersion;
 use feruca::{Collator, Tailoring};
 use std::cmp::Ordering;
 
@@ -63,6 +70,39 @@ fn cldr_shifted() {
     conformance(path, &mut collator);
 }
 
+#[cfg(any(
+    feature = "unicode-14",
+    feature = "unicode-15",
+    feature = "cldr-43",
+    feature = "cldr-44"
+))]
+#[test]
+fn legacy() {
+    let versions = [
+        #[cfg(feature = "unicode-14")]
+        (TableVersion::Unicode14, "14"),
+        #[cfg(feature = "unicode-15")]
+        (TableVersion::Unicode15, "15"),
+        #[cfg(feature = "cldr-43")]
+        (TableVersion::Cldr43, "cldr-43"),
+        #[cfg(feature = "cldr-44")]
+        (TableVersion::Cldr44, "cldr-44"),
+    ];
+
+    for (version, dir) in versions {
+        for (tailoring, table) in [
+            (Tailoring::DucetLegacy(version), ""),
+            (Tailoring::CldrLegacy(version), "CLDR_"),
+        ] {
+            for (shifting, strategy) in [(false, "NON_IGNORABLE"), (true, "SHIFTED")] {
+                let path = format!("test-data/{dir}/CollationTest_{table}{strategy}_SHORT.txt");
+                let mut collator = Collator::new(tailoring, shifting, false);
+                conformance(&path, &mut collator);
+            }
+        }
+    }
+}
+
 #[cfg(feature = "pipeline-stats")]
 #[test]
 fn lazy_utf8_primary_path_conforms() {
@@ -32,6 +32,217 @@ fn capitalization() {
     assert_eq!(comp, Ordering::Less);
 }
 
+#[cfg(feature = "chinese")]
+#[test]
+fn chinese() {
+    let names = [
+        "张三", "李四", "王五", "赵六", "阿姨", "中国", "一", "北京", "上海",
+    ];
+
+    let mut pinyin = Collator::new(Tailoring::Cldr(Locale::ChinesePinyin), true, false);
+    let mut words = names.to_vec();
+    words.sort_unstable_by(|a, b| pinyin.collate(a, b));
+    assert_eq!(
+        words,
+        [
+            "阿姨", "北京", "李四", "上海", "王五", "一", "张三", "赵六", "中国"
+        ]
+    );
+
+    let mut stroke = Collator::new(Tailoring::Cldr(Locale::ChineseStroke), true, false);
+    let mut words = names.to_vec();
+    words.sort_unstable_by(|a, b| stroke.collate(a, b));
+    assert_eq!(
+        words,
+        [
+            "一", "上海", "中国", "王五", "北京", "张三", "李四", "阿姨", "赵六"
+        ]
+    );
+
+    let mut zhuyin = Collator::new(Tailoring::Cldr(Locale::ChineseZhuyin), true, false);
+    let mut words = names.to_vec();
+    words.sort_unstable_by(|a, b| zhuyin.collate(a, b));
+    assert_eq!(
+        words,
+        [
+            "北京", "李四", "赵六", "张三", "中国", "上海", "阿姨", "一", "王五"
+        ]
+    );
+
+    // Han, then Bopomofo, then Latin; Han characters without a reading keep their implicit weights
+    assert_eq!(pinyin.collate("中", "ㄅ"), Ordering::Less);
+    assert_eq!(pinyin.collate("ㄅ", "a"), Ordering::Less);
+    assert_eq!(pinyin.collate("\u{20000}", "z"), Ordering::Greater);
+
+    // Pinyin tones, in order
+    let mut words = vec!["ma", "mà", "mǎ", "má", "mā"];
+    words.sort_unstable_by(|a, b| pinyin.collate(a, b));
+    assert_eq!(words, ["mā", "má", "mǎ", "mà", "ma"]);
+
+    // The same with decomposed tones, or with a tone after another mark
+    assert_eq!(pinyin.collate("a", "a\u{300}"), Ordering::Greater);
+    assert_eq!(pinyin.collate("o", "ǭ"), Ordering::Greater);
+    assert_eq!(pinyin.collate("o", "o\u{328}\u{304}"), Ordering::Greater);
+    for collator in [&mut pinyin, &mut stroke, &mut zhuyin] {
+        assert_eq!(
+            collator.collate("į\u{300}", "i\u{328}\u{300}"),
+            Ordering::Equal
+        );
+        assert_eq!(
+            collator.collate("ų\u{30C}", "u\u{328}\u{30C}"),
+            Ordering::Equal
+        );
+    }
+}
+
+#[cfg(all(feature = "czech", feature = "slovak"))]
+#[test]
+fn czech_slovak() {
+    let mut words = vec![
+        "chata", "hrad", "cena", "čaj", "izba", "řeka", "ruka", "Chrudim",
+    ];
+    let expected = [
+        "cena", "čaj", "hrad", "chata", "Chrudim", "izba", "ruka", "řeka",
+    ];
+
+    for locale in [Locale::Czech, Locale::Slovak] {
+        let mut collator = Collator::new(Tailoring::Cldr(locale), true, true);
+        words.sort_unstable_by(|a, b| collator.collate(a, b));
+        assert_eq!(words, expected, "{locale:?}");
+
+        // The contraction applies to plain ASCII, in both strategies
+        assert_eq!(collator.collate("ch", "hz"), Ordering::Greater);
+        let mut non_ignorable = Collator::new(Tailoring::Cldr(locale), false, true);
+        assert_eq!(non_ignorable.collate("chx", "cx"), Ordering::Greater);
+        assert_eq!(non_ignorable.collate("CH", "Ch"), Ordering::Greater);
+
+        // Long inputs take the streaming path
+        let a = "chata ".repeat(10);
+        let b = "hrad ".repeat(10);
+        assert_eq!(collator.collate(&a, &b), Ordering::Greater);
+
+        // A precomposed letter that ends the contraction sorts as its decomposition
+        let mut collator = Collator::new(Tailoring::Cldr(locale), true, false);
+        assert_eq!(collator.collate("cĥa", "ch\u{302}a"), Ordering::Equal);
+        assert_eq!(collator.collate("Cḩ", "Ch\u{327}"), Ordering::Equal);
+    }
+
+    let mut slovak = Collator::new(Tailoring::Cldr(Locale::Slovak), true, true);
+    assert_eq!(slovak.collate("ä", "az"), Ordering::Greater);
+    let mut slovak = Collator::new(Tailoring::Cldr(Locale::Slovak), true, false);
+    assert_eq!(
+        slovak.collate("a\u{344}", "a\u{308}\u{301}"),
+        Ordering::Equal
+    );
+}
+
+#[cfg(all(feature = "danish", feature = "norwegian"))]
+#[test]
+fn danish_norwegian() {
+    let mut names = vec![
+        "Øjvind", "Aabenraa", "anders", "Ærø", "Zealand", "Åse", "Anders", "Andersen",
+    ];
+
+    let mut danish = Collator::new(Tailoring::Cldr(Locale::Danish), true, true);
+    names.sort_unstable_by(|a, b| danish.collate(a, b));
+    assert_eq!(
+        names,
+        [
+            "Anders", "anders", "Andersen", "Zealand", "Ærø", "Øjvind", "Aabenraa", "Åse"
+        ]
+    );
+
+    let mut norwegian = Collator::new(Tailoring::Cldr(Locale::NorwegianBokmal), true, true);
+    names.sort_unstable_by(|a, b| norwegian.collate(a, b));
+    assert_eq!(
+        names,
+        [
+            "anders", "Anders", "Andersen", "Zealand", "Ærø", "Øjvind", "Aabenraa", "Åse"
+        ]
+    );
+
+    // Uppercase first applies beyond ASCII, too
+    assert_eq!(danish.collate("Æble", "æble"), Ordering::Less);
+    assert_eq!(norwegian.collate("Æble", "æble"), Ordering::Greater);
+
+    // "aa" differs from "å" only at the tertiary level
+    assert_eq!(danish.collate("Åse", "Aase"), Ordering::Less);
+
+    let mut non_ignorable = Collator::new(Tailoring::Cldr(Locale::Danish), false, true);
+    assert_eq!(
+        non_ignorable.collate("Aabenraa", "Zealand"),
+        Ordering::Greater
+    );
+
+    // A precomposed letter after the start of a contraction sorts as its decomposition
+    for locale in [
+        Locale::Danish,
+        Locale::NorwegianBokmal,
+        Locale::NorwegianNynorsk,
+    ] {
+        let mut collator = Collator::new(Tailoring::Cldr(locale), false, false);
+        assert_eq!(collator.collate("Aå", "Aa\u{30A}"), Ordering::Equal);
+        assert_eq!(collator.collate("aä", "aa\u{308}"), Ordering::Equal);
+        assert_eq!(collator.collate("aáb", "aa\u{301}b"), Ordering::Equal);
+    }
+}
+
+#[cfg(feature = "emoji")]
+#[test]
+fn emoji() {
+    let mut emoji = vec![
+        "🇺🇸",
+        "🍎",
+        "👋🏽",
+        "🐶",
+        "#️⃣",
+        "😀",
+        "👨‍👩‍👧",
+        "👋",
+        "🚗",
+        "👨‍👩‍👧‍👦",
+        "😃",
+        "🤚",
+    ];
+
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Emoji), true, false);
+    emoji.sort_unstable_by(|a, b| collator.collate(a, b));
+
+    assert_eq!(
+        emoji,
+        [
+            "😀",
+            "😃",
+            "👋",
+            "👋🏽",
+            "🤚",
+            "👨‍👩‍👧",
+            "👨‍👩‍👧‍👦",
+            "🐶",
+            "🍎",
+            "🚗",
+            "#️⃣",
+            "🇺🇸"
+        ]
+    );
+
+    // Emoji follow the letters, and a sequence sorts as one, with or without its variation
+    // selectors
+    assert_eq!(collator.collate("😀", "z"), Ordering::Greater);
+    assert_eq!(collator.collate("1\u{20E3}", "2"), Ordering::Greater);
+    assert_eq!(collator.collate("❤️‍🔥", "❤\u{200D}🔥"), Ordering::Equal);
+    assert_eq!(
+        collator.collate("👩‍❤️‍💋‍👨".as_bytes(), "👩‍❤️‍👨".as_bytes()),
+        Ordering::Less
+    );
+
+    // A precomposed symbol that ends a sequence sorts as its decomposition
+    assert_eq!(
+        collator.collate("🙂\u{200D}\u{21AE}", "🙂\u{200D}\u{2194}\u{338}"),
+        Ordering::Equal
+    );
+}
+
 #[test]
 fn fdfa() {
     // This will panic if the CEA length is not doubled early enough.
@@ -43,3 +254,664 @@ fn fdfa() {
     let comp = collator.collate(a, b);
     assert_eq!(comp, Ordering::Greater);
 }
+
+#[cfg(feature = "german-phonebook")]
+#[test]
+fn german_phonebook() {
+    let mut names = vec!["Muller", "Mütze", "Muffe", "Müller", "Mueller"];
+
+    let mut standard = Collator::new(Tailoring::Cldr(Locale::German), true, true);
+    names.sort_unstable_by(|a, b| standard.collate(a, b));
+    assert_eq!(names, ["Mueller", "Muffe", "Muller", "Müller", "Mütze"]);
+
+    let mut phonebook = Collator::new(Tailoring::Cldr(Locale::GermanPhonebook), true, true);
+    names.sort_unstable_by(|a, b| phonebook.collate(a, b));
+    assert_eq!(names, ["Mueller", "Müller", "Mütze", "Muffe", "Muller"]);
+
+    // Decomposed input gets the same treatment
+    let mut phonebook = Collator::new(Tailoring::Cldr(Locale::GermanPhonebook), true, false);
+    assert_eq!(
+        phonebook.collate("Mu\u{308}ller", "Müller"),
+        Ordering::Equal
+    );
+    assert_eq!(phonebook.collate("Ö", "Oez"), Ordering::Less);
+
+    // Also where the letter is precomposed with another mark
+    assert_eq!(
+        phonebook.collate("ụ\u{308}", "u\u{323}\u{308}"),
+        Ordering::Equal
+    );
+}
+
+#[cfg(all(
+    feature = "greek",
+    feature = "russian",
+    feature = "bulgarian",
+    feature = "ukrainian",
+    feature = "serbian"
+))]
+#[test]
+fn greek_and_cyrillic() {
+    let mut words = vec!["Zeus", "Ωκεανός", "Athena", "Άρτεμις", "Δίας"];
+
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Greek), true, false);
+    words.sort_unstable_by(|a, b| collator.collate(a, b));
+    assert_eq!(words, ["Άρτεμις", "Δίας", "Ωκεανός", "Athena", "Zeus"]);
+
+    let mut words = vec!["Moscow", "Москва", "Київ", "Kyiv", "Яблуко", "Ґанок", "Гай"];
+
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Russian), true, false);
+    words.sort_unstable_by(|a, b| collator.collate(a, b));
+    assert_eq!(
+        words,
+        ["Гай", "Ґанок", "Київ", "Москва", "Яблуко", "Kyiv", "Moscow"]
+    );
+
+    // In Ukrainian, "ґ" and "ї" are letters of their own, however the diaeresis is written
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Ukrainian), true, false);
+    assert_eq!(collator.collate("ґа", "гя"), Ordering::Greater);
+    assert_eq!(collator.collate("їа", "ія"), Ordering::Greater);
+    assert_eq!(collator.collate("Яблуко", "Apple"), Ordering::Less);
+    assert_eq!(
+        collator.collate("і\u{344}", "і\u{308}\u{301}"),
+        Ordering::Equal
+    );
+
+    // In Serbian, "й" is only a variant of "и"
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Serbian), true, false);
+    assert_eq!(collator.collate("йа", "иб"), Ordering::Less);
+    assert_eq!(collator.collate("и\u{306}а", "иб"), Ordering::Less);
+
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Bulgarian), true, false);
+    assert_eq!(collator.collate("йа", "иб"), Ordering::Greater);
+    assert_eq!(collator.collate("Ябълка", "Apple"), Ordering::Less);
+}
+
+#[cfg(feature = "hebrew")]
+#[test]
+fn hebrew() {
+    let mut words = vec![
+        "Tel Aviv",
+        "תל אביב",
+        "Haifa",
+        "חיפה",
+        "ירושלים",
+        "Jerusalem",
+    ];
+
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Hebrew), true, false);
+    words.sort_unstable_by(|a, b| collator.collate(a, b));
+    assert_eq!(
+        words,
+        [
+            "חיפה",
+            "ירושלים",
+            "תל אביב",
+            "Haifa",
+            "Jerusalem",
+            "Tel Aviv"
+        ]
+    );
+
+    // The geresh and gershayim sort just before the apostrophe and quotation mark; niqqud are
+    // secondary differences
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Hebrew), false, false);
+    assert_eq!(collator.collate("ג׳", "ג'"), Ordering::Less);
+    assert_eq!(collator.collate("צה״ל", "צה\"ל"), Ordering::Less);
+    assert_eq!(collator.collate("שָׁלוֹם", "שלום"), Ordering::Greater);
+    assert_eq!(collator.collate("שָׁלוֹם", "שלומי"), Ordering::Less);
+}
+
+#[cfg(feature = "hungarian")]
+#[test]
+fn hungarian() {
+    let mut words = vec![
+        "cukor", "csak", "cica", "dzsungel", "dzéta", "dob", "döntés", "ölel", "orr", "őz", "ütő",
+        "utca", "szép", "sör", "zsír", "zab", "hoszt", "hosszú",
+    ];
+
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Hungarian), true, false);
+    words.sort_unstable_by(|a, b| collator.collate(a, b));
+
+    assert_eq!(
+        words,
+        [
+            "cica", "cukor", "csak", "dob", "döntés", "dzéta", "dzsungel", "hosszú", "hoszt",
+            "orr", "ölel", "őz", "sör", "szép", "utca", "ütő", "zab", "zsír"
+        ]
+    );
+
+    // A doubled digraph is two of that letter, however it is written
+    assert_eq!(collator.collate("hosszú", "hoszszú"), Ordering::Equal);
+    assert_eq!(collator.collate("ccs", "cscs"), Ordering::Equal);
+    assert_eq!(collator.collate("Ccs", "CsCs"), Ordering::Greater);
+    assert_eq!(collator.collate("kasza", "kassza"), Ordering::Less);
+    assert_eq!(
+        collator.collate("ddzs".as_bytes(), "dzsdzs".as_bytes()),
+        Ordering::Equal
+    );
+
+    // A precomposed letter that ends a digraph sorts as its decomposition
+    assert_eq!(collator.collate("cşd", "cs\u{327}d"), Ordering::Equal);
+    assert_eq!(collator.collate("zşa", "zs\u{327}a"), Ordering::Equal);
+    assert_eq!(collator.collate("dzşa", "dzs\u{327}a"), Ordering::Equal);
+}
+
+#[cfg(all(feature = "hindi", feature = "bengali", feature = "tamil"))]
+#[test]
+fn indic() {
+    let mut hindi = Collator::new(Tailoring::Cldr(Locale::Hindi), true, false);
+    assert_eq!(hindi.collate("कँ", "कं"), Ordering::Greater);
+    assert_eq!(hindi.collate("कं", "कः"), Ordering::Less);
+
+    let mut bengali = Collator::new(Tailoring::Cldr(Locale::Bengali), true, false);
+    assert_eq!(bengali.collate("কং", "কঃ"), Ordering::Less);
+    assert_eq!(bengali.collate("কঁক", "কঃখ"), Ordering::Greater);
+
+    // Tamil consonants in the traditional order, each bare (with a virama) before its syllable
+    let mut words = vec!["ஹ", "க", "ச", "க்ஷ", "ஜ", "ங", "க்", "கா", "ன"];
+    let mut tamil = Collator::new(Tailoring::Cldr(Locale::Tamil), true, false);
+    words.sort_unstable_by(|a, b| tamil.collate(a, b));
+    assert_eq!(words, ["க்", "க", "கா", "ங", "ச", "ன", "ஜ", "ஹ", "க்ஷ"]);
+
+    // Nukta consonants, precomposed or not, are the same letter
+    assert_eq!(hindi.collate("क़", "क\u{93C}"), Ordering::Equal);
+
+    // A nukta consonant is a secondary variant of the plain one, and a conjunct (formed with a
+    // virama) sorts after all the syllables of its first consonant
+    let mut words = vec!["ख", "क्ष", "क़ा", "क्", "कि", "क", "क्क", "का", "क़"];
+    words.sort_unstable_by(|a, b| hindi.collate(a, b));
+    assert_eq!(words, ["क", "क़", "का", "क़ा", "कि", "क्", "क्क", "क्ष", "ख"]);
+    assert_eq!(hindi.collate("ड़", "ढ"), Ordering::Less);
+
+    let mut words = vec!["খ", "ক্ষ", "য়", "ক্", "কি", "ক", "ক্ক", "কা", "য", "র"];
+    words.sort_unstable_by(|a, b| bengali.collate(a, b));
+    assert_eq!(words, ["ক", "কা", "কি", "ক্", "ক্ক", "ক্ষ", "খ", "য", "য়", "র"]);
+    assert_eq!(
+        bengali.collate("\u{9DF}", "\u{9AF}\u{9BC}"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        bengali.collate("\u{9DC}", "\u{9A1}\u{9BC}"),
+        Ordering::Equal
+    );
+
+    // Each script sorts before the Latin script
+    assert_eq!(hindi.collate("क", "a"), Ordering::Less);
+    assert_eq!(bengali.collate("ক", "a"), Ordering::Less);
+    assert_eq!(tamil.collate("க", "a"), Ordering::Less);
+}
+
+#[cfg(feature = "japanese")]
+#[test]
+fn japanese() {
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Japanese), true, false);
+
+    // Gojūon order, with kanji after all kana (in JIS X 0208 order, not by code point)
+    let mut words = vec![
+        "わたし",
+        "さくら",
+        "ねこ",
+        "あめ",
+        "まつり",
+        "かき",
+        "いぬ",
+        "やま",
+        "はな",
+        "らくご",
+    ];
+    words.sort_unstable_by(|a, b| collator.collate(a, b));
+    assert_eq!(
+        words,
+        [
+            "あめ",
+            "いぬ",
+            "かき",
+            "さくら",
+            "ねこ",
+            "はな",
+            "まつり",
+            "やま",
+            "らくご",
+            "わたし"
+        ]
+    );
+    assert_eq!(collator.collate("ん", "亜"), Ordering::Less);
+    assert_eq!(collator.collate("阿", "愛"), Ordering::Less);
+
+    // Voicing at the secondary level; small kana, then hiragana before katakana, at the tertiary
+    assert_eq!(collator.collate("ばa", "はb"), Ordering::Less);
+    assert_eq!(collator.collate("は", "ば"), Ordering::Less);
+    assert_eq!(collator.collate("ば", "ぱ"), Ordering::Less);
+    assert_eq!(collator.collate("っ", "つ"), Ordering::Less);
+    assert_eq!(collator.collate("あ", "ア"), Ordering::Less);
+
+    // The prolonged sound mark repeats the vowel before it; the iteration marks, the kana
+    assert_eq!(collator.collate("カー", "カア"), Ordering::Less);
+    assert_eq!(collator.collate("カア", "カイ"), Ordering::Less);
+    assert_eq!(collator.collate("ガー", "ガイ"), Ordering::Less);
+    assert_eq!(collator.collate("こゝろ", "こころ"), Ordering::Less);
+    assert_eq!(collator.collate("こころ", "こさ"), Ordering::Less);
+    assert_eq!(collator.collate("いすゞ", "いすず"), Ordering::Less);
+
+    // With the hiragana quaternary, other tertiary differences come first
+    let mut quaternary = Collator::new(
+        Tailoring::Cldr(Locale::JapaneseHiraganaQuaternary),
+        true,
+        false,
+    );
+    assert_eq!(collator.collate("アa", "あA"), Ordering::Greater);
+    assert_eq!(quaternary.collate("アa", "あA"), Ordering::Less);
+    assert_eq!(quaternary.collate("あ", "ア"), Ordering::Less);
+    assert_eq!(quaternary.collate("ぁア", "ァあ"), Ordering::Less);
+}
+
+#[cfg(feature = "korean")]
+#[test]
+fn korean() {
+    let mut names = vec![
+        "박지성",
+        "朴",
+        "김연아",
+        "이순신",
+        "李",
+        "가",
+        "家",
+        "각",
+        "고",
+        "나",
+    ];
+
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Korean), true, false);
+    names.sort_unstable_by(|a, b| collator.collate(a, b));
+
+    // Hanja follow the syllables they are read as: 家 (가), 李 (리), 朴 (박)
+    assert_eq!(
+        names,
+        [
+            "가",
+            "家",
+            "각",
+            "고",
+            "김연아",
+            "나",
+            "李",
+            "朴",
+            "박지성",
+            "이순신"
+        ]
+    );
+
+    // Precomposed and conjoining-jamo syllables are still equal
+    assert_eq!(
+        collator.collate("각", "\u{1100}\u{1161}\u{11A8}"),
+        Ordering::Equal
+    );
+
+    // Hangul, and Hanja with their readings, sort before the Latin script
+    let mut words = vec!["Seoul", "서울", "apple", "金", "김치", "Zebra"];
+    words.sort_unstable_by(|a, b| collator.collate(a, b));
+    assert_eq!(words, ["金", "김치", "서울", "apple", "Seoul", "Zebra"]);
+    assert_eq!(collator.collate("힣", "a"), Ordering::Less);
+    assert_eq!(collator.collate("\u{20000}", "z"), Ordering::Greater);
+}
+
+#[cfg(feature = "lithuanian")]
+#[test]
+fn lithuanian() {
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Lithuanian), true, false);
+    let mut words = vec!["dar", "yla", "čia", "ilgas", "cukrus", "ikas"];
+    words.sort_unstable_by(|a, b| collator.collate(a, b));
+    assert_eq!(words, ["cukrus", "čia", "dar", "ikas", "yla", "ilgas"]);
+
+    // "y" differs from "i" only at the secondary level
+    assert_eq!(collator.collate("ya", "ia"), Ordering::Greater);
+    assert_eq!(collator.collate("ya", "ib"), Ordering::Less);
+
+    // An explicit dot above on a soft-dotted "i" is absorbed by a following accent...
+    assert_eq!(
+        collator.collate("i\u{307}\u{301}", "\u{ed}"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        collator.collate("i\u{316}\u{307}\u{301}", "\u{ed}\u{316}"),
+        Ordering::Equal
+    );
+
+    // ...but not on "I," where the "I\u{307}" contraction takes it first, even discontiguously
+    assert_eq!(
+        collator.collate("I\u{307}\u{301}", "\u{cd}"),
+        Ordering::Greater
+    );
+    assert_ne!(
+        collator.collate("\u{130}\u{316}\u{301}", "\u{cd}\u{316}"),
+        Ordering::Equal
+    );
+
+    // Precomposed letters with a mark before the caron, or a dot above, sort as decomposed
+    assert_eq!(
+        collator.collate("ç\u{30C}", "c\u{327}\u{30C}"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        collator.collate("Ċ\u{300}", "C\u{307}\u{300}"),
+        Ordering::Equal
+    );
+}
+
+#[cfg(feature = "persian")]
+#[test]
+fn persian() {
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Persian), true, false);
+
+    // Arabic script before Latin
+    assert_eq!(collator.collate("ب", "a"), Ordering::Less);
+
+    // Alif with madda before alif; hamza after it
+    assert_eq!(collator.collate("آب", "اب"), Ordering::Less);
+    assert_eq!(collator.collate("اب", "ءا"), Ordering::Less);
+    assert_eq!(collator.collate("ءا", "ب"), Ordering::Less);
+
+    // Arabic kāf and yā’ differ from the Persian forms only at the secondary level
+    assert_eq!(collator.collate("كتاب", "کتابی"), Ordering::Less);
+    assert_eq!(collator.collate("کتاب", "كتاب"), Ordering::Less);
+    assert_eq!(collator.collate("علي", "علیا"), Ordering::Less);
+
+    let info = feruca::TableInfo::new(Tailoring::Cldr(Locale::Persian));
+    let primary = |cp| info.weights(&[cp]).unwrap()[0].primary;
+    assert_eq!(primary(0x643), primary(0x6A9));
+    assert_eq!(primary(0x64A), primary(0x6CC));
+}
+
+#[cfg(feature = "polish")]
+#[test]
+fn polish() {
+    let mut words = vec![
+        "żaba", "łódź", "zebra", "lato", "źle", "ćma", "cel", "ósmy", "owca",
+    ];
+
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Polish), true, true);
+    words.sort_unstable_by(|a, b| collator.collate(a, b));
+    assert_eq!(
+        words,
+        [
+            "cel", "ćma", "lato", "łódź", "owca", "ósmy", "zebra", "źle", "żaba"
+        ]
+    );
+
+    // A precomposed letter sorts as its decomposition, even with another mark before the acute
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Polish), true, false);
+    assert_eq!(collator.collate("ḉ", "c\u{327}\u{301}"), Ordering::Equal);
+    assert_eq!(collator.collate("Ớ", "O\u{31B}\u{301}"), Ordering::Equal);
+    assert_eq!(collator.collate("ḉa", "ćb"), Ordering::Less);
+    assert_eq!(
+        collator.collate("Ç\u{301}", "C\u{327}\u{301}"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        collator.collate("ņ\u{301}", "n\u{327}\u{301}"),
+        Ordering::Equal
+    );
+}
+
+#[cfg(feature = "spanish")]
+#[test]
+fn spanish() {
+    let mut words = vec![
+        "llama", "nube", "luz", "chico", "ñu", "cosa", "oso", "Ñandú",
+    ];
+
+    let mut modern = Collator::new(Tailoring::Cldr(Locale::Spanish), true, true);
+    words.sort_unstable_by(|a, b| modern.collate(a, b));
+    assert_eq!(
+        words,
+        [
+            "chico", "cosa", "llama", "luz", "nube", "Ñandú", "ñu", "oso"
+        ]
+    );
+
+    let mut traditional = Collator::new(Tailoring::Cldr(Locale::SpanishTraditional), true, true);
+    words.sort_unstable_by(|a, b| traditional.collate(a, b));
+    assert_eq!(
+        words,
+        [
+            "cosa", "chico", "luz", "llama", "nube", "Ñandú", "ñu", "oso"
+        ]
+    );
+
+    // The root contraction for l with a middle dot still applies, so "l·l" is two letters
+    assert_eq!(traditional.collate("l·l", "ll"), Ordering::Less);
+    assert_eq!(traditional.collate("Llama", "llama"), Ordering::Greater);
+
+    // A precomposed letter sorts as its decomposition, inside a contraction or before a tilde
+    let mut modern = Collator::new(Tailoring::Cldr(Locale::Spanish), true, false);
+    assert_eq!(
+        modern.collate("ņ\u{303}", "n\u{327}\u{303}"),
+        Ordering::Equal
+    );
+    let mut traditional = Collator::new(Tailoring::Cldr(Locale::SpanishTraditional), true, false);
+    assert_eq!(traditional.collate("cĥa", "ch\u{302}a"), Ordering::Equal);
+    assert_eq!(traditional.collate("Lḽ", "Ll\u{32D}"), Ordering::Equal);
+}
+
+#[cfg(all(feature = "swedish", feature = "finnish"))]
+#[test]
+fn swedish_finnish() {
+    let expected = [
+        "Anna", "Ola", "vb", "wa", "Über", "Yngve", "Zorro", "Åsa", "Ärla", "Örjan",
+    ];
+
+    for locale in [Locale::Swedish, Locale::Finnish] {
+        let mut names = vec![
+            "Örjan", "Åsa", "wa", "Zorro", "Ärla", "Über", "vb", "Anna", "Yngve", "Ola",
+        ];
+
+        let mut collator = Collator::new(Tailoring::Cldr(locale), true, true);
+        names.sort_unstable_by(|a, b| collator.collate(a, b));
+        assert_eq!(names, expected, "{locale:?}");
+
+        let mut collator = Collator::new(Tailoring::Cldr(locale), true, false);
+        assert_eq!(collator.collate("A\u{30A}sa", "Åsa"), Ordering::Equal);
+        assert_eq!(collator.collate("Åsa", "Zsa"), Ordering::Greater);
+
+        // Decomposed input sorts as precomposed, even where an ASCII letter alone would decide
+        assert_eq!(collator.collate("b", "a\u{308}b"), Ordering::Less);
+        assert_eq!(collator.collate("b", "äb"), Ordering::Less);
+        assert_eq!(collator.collate("zz", "o\u{308}"), Ordering::Less);
+        assert_eq!(
+            collator.collate("a\u{30A}".as_bytes(), "b".as_bytes()),
+            Ordering::Greater
+        );
+
+        let mut names = vec!["A\u{30A}sa", "Zorro", "Adam", "O\u{308}sten", "Åsa"];
+        names.sort_by(|a, b| collator.collate(a, b));
+        assert_eq!(
+            names,
+            ["Adam", "Zorro", "A\u{30A}sa", "Åsa", "O\u{308}sten"],
+            "{locale:?}"
+        );
+
+        // The same goes for a letter with another mark below, or with two marks in one
+        assert_eq!(
+            collator.collate("Ą\u{308}", "A\u{328}\u{308}"),
+            Ordering::Equal
+        );
+        assert_eq!(
+            collator.collate("ų\u{30A}", "u\u{328}\u{30A}"),
+            Ordering::Equal
+        );
+        assert_eq!(
+            collator.collate("o\u{344}", "o\u{308}\u{301}"),
+            Ordering::Equal
+        );
+    }
+}
+
+#[cfg(all(feature = "thai", feature = "lao"))]
+#[test]
+fn thai_and_lao() {
+    let mut words = vec!["ไก่", "ขาว", "เขา", "กา", "แก", "กิน", "ก้า", "กำ", "ก่า"];
+
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Thai), true, false);
+    words.sort_unstable_by(|a, b| collator.collate(a, b));
+    assert_eq!(
+        words,
+        ["กา", "ก่า", "ก้า", "กำ", "กิน", "แก", "ไก่", "ขาว", "เขา"]
+    );
+
+    // A prevowel sorts after the consonant that follows it, even past a shared prefix
+    assert_eq!(collator.collate("เก", "เข"), Ordering::Less);
+    assert_eq!(
+        collator.collate("เขา".as_bytes(), "กา".as_bytes()),
+        Ordering::Greater
+    );
+
+    // The thanthakhat comes first among the signs, and the repetition mark is punctuation
+    let mut marks = vec!["ก่", "ก็", "ก์", "กๆ", "ก"];
+    marks.sort_unstable_by(|a, b| collator.collate(a, b));
+    assert_eq!(marks, ["ก", "กๆ", "ก์", "ก็", "ก่"]);
+    assert_eq!(collator.collate("ๆ", "ๆ\u{E4E}"), Ordering::Equal);
+
+    // The Thai script sorts before the Latin script
+    assert_eq!(collator.collate("ก", "a"), Ordering::Less);
+    assert_eq!(collator.collate("เก", "z"), Ordering::Less);
+
+    let mut words = vec!["ເກ", "ກາ", "ຂາ", "ແກ"];
+
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Lao), true, false);
+    words.sort_unstable_by(|a, b| collator.collate(a, b));
+    assert_eq!(words, ["ກາ", "ເກ", "ແກ", "ຂາ"]);
+
+    // The same goes for the Lao script
+    assert_eq!(collator.collate("ກ", "a"), Ordering::Less);
+    assert_eq!(collator.collate("ເກ", "z"), Ordering::Less);
+
+    let mut root = Collator::new(Tailoring::Cldr(Locale::Root), true, false);
+    assert_eq!(root.collate("ກ", "a"), Ordering::Greater);
+}
+
+#[cfg(all(feature = "turkish", feature = "azerbaijani"))]
+#[test]
+fn turkish_azerbaijani() {
+    let mut names = vec![
+        "İstanbul",
+        "Işık",
+        "Çağla",
+        "idil",
+        "Hakan",
+        "Cem",
+        "Zeki",
+        "ılık",
+    ];
+
+    let mut turkish = Collator::new(Tailoring::Cldr(Locale::Turkish), true, true);
+    names.sort_unstable_by(|a, b| turkish.collate(a, b));
+    assert_eq!(
+        names,
+        [
+            "Cem",
+            "Çağla",
+            "Hakan",
+            "ılık",
+            "Işık",
+            "idil",
+            "İstanbul",
+            "Zeki"
+        ]
+    );
+
+    // Plain ASCII is affected, too
+    assert_eq!(turkish.collate("Ij", "ii"), Ordering::Less);
+    assert_eq!(turkish.collate("Iz", "ia"), Ordering::Less);
+    assert_eq!(turkish.collate("Ĩz", "ia"), Ordering::Less);
+
+    let mut azerbaijani = Collator::new(Tailoring::Cldr(Locale::Azerbaijani), true, true);
+    let mut words = vec!["xalq", "qala", "həyat", "kitab", "zəng", "web"];
+    words.sort_unstable_by(|a, b| azerbaijani.collate(a, b));
+    assert_eq!(words, ["həyat", "xalq", "kitab", "qala", "zəng", "web"]);
+
+    // A precomposed letter sorts as its decomposition, with another mark before the tailored one
+    for locale in [Locale::Turkish, Locale::Azerbaijani] {
+        let mut collator = Collator::new(Tailoring::Cldr(locale), true, false);
+        assert_eq!(
+            collator.collate("ģ\u{306}", "g\u{327}\u{306}"),
+            Ordering::Equal
+        );
+        assert_eq!(
+            collator.collate("Į\u{307}", "I\u{328}\u{307}"),
+            Ordering::Equal
+        );
+        assert_eq!(
+            collator.collate("u\u{344}", "u\u{308}\u{301}"),
+            Ordering::Equal
+        );
+    }
+}
+
+#[cfg(all(feature = "urdu", feature = "pashto"))]
+#[test]
+fn urdu_pashto() {
+    let mut urdu = Collator::new(Tailoring::Cldr(Locale::Urdu), true, false);
+    let mut words = vec!["ٹوپی", "تین", "بھائی", "بس", "پانی", "ثمر"];
+    words.sort_unstable_by(|a, b| urdu.collate(a, b));
+    assert_eq!(words, ["بس", "بھائی", "پانی", "تین", "ٹوپی", "ثمر"]);
+
+    // Arabic script before Latin
+    assert_eq!(urdu.collate("ے", "a"), Ordering::Less);
+
+    // Heh with yeh above sorts as its decomposition, ae with hamza above
+    assert_eq!(urdu.collate("ۀ", "ە\u{654}"), Ordering::Equal);
+    assert_eq!(urdu.collate("ۀ\u{653}", "ە\u{654}\u{653}"), Ordering::Equal);
+
+    let mut pashto = Collator::new(Tailoring::Cldr(Locale::Pashto), true, false);
+    let mut words = vec!["چرګ", "ځای", "جوړ", "ښار", "شین", "سړی", "ټول", "ثور"];
+    words.sort_unstable_by(|a, b| pashto.collate(a, b));
+    assert_eq!(
+        words,
+        ["ټول", "ثور", "جوړ", "ځای", "چرګ", "سړی", "شین", "ښار"]
+    );
+    assert_eq!(pashto.collate("ۍ", "ې"), Ordering::Greater);
+}
+
+#[cfg(feature = "vietnamese")]
+#[test]
+fn vietnamese() {
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Vietnamese), true, false);
+    let mut words = vec![
+        "đi", "ân", "dê", "ưa", "ăn", "uống", "an", "ơn", "ong", "ốc",
+    ];
+    words.sort_unstable_by(|a, b| collator.collate(a, b));
+    assert_eq!(
+        words,
+        [
+            "an", "ăn", "ân", "dê", "đi", "ong", "ốc", "ơn", "uống", "ưa"
+        ]
+    );
+
+    // The tones, in order: none, grave, hook above, tilde, acute, dot below
+    let mut words = vec!["mạ", "má", "mã", "mả", "mà", "ma"];
+    words.sort_unstable_by(|a, b| collator.collate(a, b));
+    assert_eq!(words, ["ma", "mà", "mả", "mã", "má", "mạ"]);
+
+    // Letter before tone, regardless of how the marks are encoded or ordered
+    assert_eq!(collator.collate("ệ", "e\u{302}\u{323}"), Ordering::Equal);
+    assert_eq!(collator.collate("e\u{323}\u{302}", "ê"), Ordering::Greater);
+    assert_eq!(collator.collate("e\u{323}\u{302}", "ef"), Ordering::Greater);
+
+    // The tone marks are tailored, so precomposed letters that carry them (even letters that are
+    // not Vietnamese) must sort as their decompositions do
+    let mut collator = Collator::new(Tailoring::Cldr(Locale::Vietnamese), false, false);
+    for (nfc, nfd) in [
+        ("ñ", "n\u{303}"),
+        ("sğ", "sg\u{306}"),
+        ("ǹ", "n\u{300}"),
+        ("ỳ", "y\u{300}"),
+        ("ằ", "a\u{306}\u{300}"),
+        ("ẩ", "a\u{302}\u{309}"),
+        ("ỗ", "o\u{302}\u{303}"),
+        ("ớ", "o\u{31B}\u{301}"),
+        ("ự", "u\u{31B}\u{323}"),
+    ] {
+        assert_eq!(collator.collate(nfc, nfd), Ordering::Equal);
+    }
+}
@@ -0,0 +1,183 @@
+#[cfg(any(
+    feature = "unicode-14",
+    feature = "unicode-15",
+    feature = "cldr-43",
+    feature = "cldr-44"
+))]
+use feruca::TableVersion;
+use feruca::{CollationElement, Locale, TableInfo, Tailoring};
+
+#[test]
+fn validate_all() {
+    let tailorings = [
+        Tailoring::Ducet,
+        Tailoring::Cldr(Locale::Root),
+        Tailoring::Cldr(Locale::ArabicScript),
+        Tailoring::Cldr(Locale::ArabicInterleaved),
+    ];
+
+    for tailoring in tailorings {
+        assert_eq!(
+            TableInfo::new(tailoring).validate(),
+            Ok(()),
+            "{tailoring:?}"
+        );
+    }
+}
+
+#[cfg(feature = "locales")]
+#[test]
+fn validate_locales() {
+    let tailorings = [
+        Tailoring::Cldr(Locale::German),
+        Tailoring::Cldr(Locale::GermanPhonebook),
+        Tailoring::Cldr(Locale::Swedish),
+        Tailoring::Cldr(Locale::Finnish),
+        Tailoring::Cldr(Locale::Danish),
+        Tailoring::Cldr(Locale::NorwegianBokmal),
+        Tailoring::Cldr(Locale::NorwegianNynorsk),
+        Tailoring::Cldr(Locale::Spanish),
+        Tailoring::Cldr(Locale::SpanishTraditional),
+        Tailoring::Cldr(Locale::Turkish),
+        Tailoring::Cldr(Locale::Azerbaijani),
+        Tailoring::Cldr(Locale::Polish),
+        Tailoring::Cldr(Locale::Czech),
+        Tailoring::Cldr(Locale::Slovak),
+        Tailoring::Cldr(Locale::Persian),
+        Tailoring::Cldr(Locale::Urdu),
+        Tailoring::Cldr(Locale::Pashto),
+        Tailoring::Cldr(Locale::Lithuanian),
+        Tailoring::Cldr(Locale::Vietnamese),
+        Tailoring::Cldr(Locale::ChinesePinyin),
+        Tailoring::Cldr(Locale::ChineseStroke),
+        Tailoring::Cldr(Locale::ChineseZhuyin),
+        Tailoring::Cldr(Locale::Japanese),
+        Tailoring::Cldr(Locale::JapaneseHiraganaQuaternary),
+        Tailoring::Cldr(Locale::Korean),
+        Tailoring::Cldr(Locale::Hindi),
+        Tailoring::Cldr(Locale::Bengali),
+        Tailoring::Cldr(Locale::Tamil),
+        Tailoring::Cldr(Locale::Hungarian),
+        Tailoring::Cldr(Locale::Emoji),
+        Tailoring::Cldr(Locale::Greek),
+        Tailoring::Cldr(Locale::Russian),
+        Tailoring::Cldr(Locale::Bulgarian),
+        Tailoring::Cldr(Locale::Ukrainian),
+        Tailoring::Cldr(Locale::Serbian),
+        Tailoring::Cldr(Locale::Hebrew),
+        Tailoring::Cldr(Locale::Thai),
+        Tailoring::Cldr(Locale::Lao),
+    ];
+
+    for tailoring in tailorings {
+        assert_eq!(
+            TableInfo::new(tailoring).validate(),
+            Ok(()),
+            "{tailoring:?}"
+        );
+    }
+}
+
+#[cfg(any(
+    feature = "unicode-14",
+    feature = "unicode-15",
+    feature = "cldr-43",
+    feature = "cldr-44"
+))]
+#[test]
+fn validate_legacy() {
+    let versions = [
+        #[cfg(feature = "unicode-14")]
+        TableVersion::Unicode14,
+        #[cfg(feature = "unicode-15")]
+        TableVersion::Unicode15,
+        #[cfg(feature = "cldr-43")]
+        TableVersion::Cldr43,
+        #[cfg(feature = "cldr-44")]
+        TableVersion::Cldr44,
+    ];
+
+    for version in versions {
+        for tailoring in [
+            Tailoring::CldrLegacy(version),
+            Tailoring::DucetLegacy(version),
+        ] {
+            assert_eq!(
+                TableInfo::new(tailoring).validate(),
+                Ok(()),
+                "{tailoring:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn weights() {
+    let info = TableInfo::new(Tailoring::Ducet);
+
+    let a = info.weights(&[0x61]).unwrap();
+    let upper_a = info.weights(&[0x41]).unwrap();
+    assert_eq!(a.len(), 1);
+    assert_eq!(a[0].primary, upper_a[0].primary);
+    assert!(a[0].tertiary < upper_a[0].tertiary);
+    assert!(!a[0].variable);
+
+    let space = info.weights(&[0x20]).unwrap();
+    assert!(space[0].variable);
+
+    // Unassigned code points get implicit weights
+    let implicit = info.weights(&[0x4E00]).unwrap();
+    assert_eq!(implicit.len(), 2);
+    assert_eq!(
+        implicit[1],
+        CollationElement {
+            primary: 0xCE00,
+            secondary: 0,
+            tertiary: 0,
+            variable: false,
+        }
+    );
+
+    // ...unless a tailoring gives them a rank in its Han order
+    #[cfg(feature = "chinese")]
+    {
+        let pinyin = TableInfo::new(Tailoring::Cldr(Locale::ChinesePinyin));
+        let ranked = pinyin.weights(&[0x4E00]).unwrap();
+        assert_eq!(ranked.len(), 2);
+        assert!(ranked[0].primary < pinyin.weights(&[0x61]).unwrap()[0].primary);
+    }
+
+    assert_eq!(info.weights(&[]), None);
+    assert_eq!(info.weights(&[0x61, 0x62]), None);
+    assert_eq!(info.weights(&[0x11_0000]), None);
+}
+
+#[test]
+fn contractions() {
+    let info = TableInfo::new(Tailoring::Cldr(Locale::Root));
+
+    let contractions: Vec<_> = info.contractions().collect();
+    assert!(contractions.contains(&vec![0x4C, 0xB7]));
+    assert!(contractions.iter().all(|seq| seq.len() >= 2));
+
+    let l_dot = info.weights(&[0x4C, 0xB7]).unwrap();
+    assert_ne!(l_dot, info.weights(&[0x4C]).unwrap());
+
+    let stats = info.stats();
+    assert_eq!(stats.contractions, contractions.len());
+    assert!(stats.longest_contraction >= 3);
+    assert!(stats.code_points > 30_000);
+}
+
+#[test]
+fn shared_primary() {
+    let info = TableInfo::new(Tailoring::Cldr(Locale::Root));
+
+    let primary = info.weights(&[0x61]).unwrap()[0].primary;
+    let sharing = info.code_points_with_primary(primary);
+
+    assert!(sharing.contains(&0x61));
+    assert!(sharing.contains(&0x41));
+    assert!(sharing.contains(&0xE1)); // á
+    assert!(!sharing.contains(&0x62));
+}
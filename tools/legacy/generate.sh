@@ -0,0 +1,24 @@
+#!/bin/sh
+# Regenerate the overlays for the earlier table versions in src/data/legacy, and the list of
+# variable code points in src/data/variable.
+#
+# The overlays are worked out from the current DUCET and CLDR root tables and from the conformance
+# tests of each version (in test-data); see src/main.rs for how. The weights of the low code points
+# (below U+00B7) for each version are printed, to be compared with the `LOW_*` arrays in
+# src/consts.rs. An output directory other than src/data can be given as the first argument.
+#
+# The variable code points are collected from every table as built into feruca, including the
+# locale tailorings, so this should be run again after ../tailor/generate.sh.
+
+set -eu
+
+cd "$(dirname "$0")"
+
+OUT=${1:-../../src/data}
+
+cargo run --release --quiet --bin legacy -- "$OUT"
+
+# The tables are read from src/data (as now built into feruca), so only update the list from there
+if [ "$OUT" = ../../src/data ]; then
+    cargo run --release --quiet --bin variable
+fi
@@ -0,0 +1,118 @@
+// Rebuild src/data/variable: the code points that have a variable weight, or a primary weight of
+// zero, in any CE of any built-in table (the current DUCET and CLDR root, every locale tailoring,
+// and every earlier version). The collator needs this to know where a shared prefix can be
+// trimmed in shifted mode.
+// usage: variable [out]   (out defaults to ../../src/data)
+//
+// feruca embeds its tables when it is built, so this reads what is in src/data; run it after the
+// legacy overlays and the tailorings have been rebuilt (generate.sh does so for the former).
+use feruca::{Locale, TableInfo, TableVersion, Tailoring};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+const DATA: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../src/data");
+
+const LOCALES: [Locale; 41] = [
+    Locale::Root,
+    Locale::ArabicScript,
+    Locale::ArabicInterleaved,
+    Locale::German,
+    Locale::GermanPhonebook,
+    Locale::Swedish,
+    Locale::Finnish,
+    Locale::Danish,
+    Locale::NorwegianBokmal,
+    Locale::NorwegianNynorsk,
+    Locale::Spanish,
+    Locale::SpanishTraditional,
+    Locale::Turkish,
+    Locale::Azerbaijani,
+    Locale::Polish,
+    Locale::Czech,
+    Locale::Slovak,
+    Locale::Persian,
+    Locale::Urdu,
+    Locale::Pashto,
+    Locale::Lithuanian,
+    Locale::Vietnamese,
+    Locale::ChinesePinyin,
+    Locale::ChineseStroke,
+    Locale::ChineseZhuyin,
+    Locale::Japanese,
+    Locale::JapaneseHiraganaQuaternary,
+    Locale::Korean,
+    Locale::Hindi,
+    Locale::Bengali,
+    Locale::Tamil,
+    Locale::Hungarian,
+    Locale::Emoji,
+    Locale::Greek,
+    Locale::Russian,
+    Locale::Bulgarian,
+    Locale::Ukrainian,
+    Locale::Serbian,
+    Locale::Hebrew,
+    Locale::Thai,
+    Locale::Lao,
+];
+
+const VERSIONS: [TableVersion; 4] = [
+    TableVersion::Unicode14,
+    TableVersion::Unicode15,
+    TableVersion::Cldr43,
+    TableVersion::Cldr44,
+];
+
+#[derive(Serialize)]
+struct VariableTable {
+    page_index: Vec<u16>,
+    pages: Vec<u64>,
+}
+
+fn main() {
+    let out = std::env::args().nth(1).unwrap_or_else(|| DATA.to_string());
+
+    let mut tailorings = vec![Tailoring::Ducet];
+    tailorings.extend(LOCALES.map(Tailoring::Cldr));
+    for version in VERSIONS {
+        tailorings.push(Tailoring::DucetLegacy(version));
+        tailorings.push(Tailoring::CldrLegacy(version));
+    }
+
+    let mut variable = BTreeSet::new();
+    for tailoring in tailorings {
+        let info = TableInfo::new(tailoring);
+        variable.extend(info.code_points().filter(|&cp| {
+            info.weights(&[cp])
+                .unwrap()
+                .iter()
+                .any(|ce| ce.variable || ce.primary == 0)
+        }));
+    }
+
+    // A bitmap of 256 code points (four words) for each page that has any, with identical pages
+    // stored once
+    let mut bitmaps: BTreeMap<usize, [u64; 4]> = BTreeMap::new();
+    for &cp in &variable {
+        let bitmap = bitmaps.entry((cp >> 8) as usize).or_default();
+        bitmap[((cp & 0xFF) >> 6) as usize] |= 1 << (cp & 0x3F);
+    }
+    let mut table = VariableTable {
+        page_index: vec![u16::MAX; 0x1100],
+        pages: vec![],
+    };
+    let mut stored: HashMap<[u64; 4], u16> = HashMap::new();
+    for (page, bitmap) in bitmaps {
+        table.page_index[page] = *stored.entry(bitmap).or_insert_with(|| {
+            table.pages.extend(bitmap);
+            (table.pages.len() / 4 - 1) as u16
+        });
+    }
+
+    std::fs::write(
+        format!("{out}/variable"),
+        postcard::to_allocvec(&table).unwrap(),
+    )
+    .unwrap();
+    eprintln!("variable: {} code points", variable.len());
+}
@@ -0,0 +1,570 @@
+// Build the overlays for the earlier table versions (src/data/legacy) from the current DUCET and
+// CLDR root tables.
+// usage: legacy [out]   (out defaults to ../../src/data)
+//
+// There are no allkeys.txt files to start from here, only the conformance tests of each version
+// (in test-data). The single code points of a NON_IGNORABLE test file (the lines "XXXX 0021") are
+// listed in the order of that version, so each overlay is worked out from the current table and
+// the test file:
+//   - Code points that the current test covers but the earlier one doesn't were not yet assigned;
+//     they are removed from the overlay (entry 0), and the Han ideographs among them get the
+//     implicit weights of unassigned code points.
+//   - The few characters whose weights changed in some other way are listed by hand in `spec`:
+//     some had primaries of their own, which they no longer have, and three Unicode 14 characters
+//     had other weights.
+//   - The primaries below the implicit weights are put in the order of the test file (by the
+//     median position of the code points whose first primary each is). As many as possible keep
+//     their current values (the longest run of them that is still in order, weighted by how often
+//     each is used); the rest are renumbered, and the variable flag follows the new values.
+// Every code point whose weights end up different gets an entry in the overlay. For the low code
+// points (below U+00B7) the weights are printed instead, as the `LOW_*` arrays in src/consts.rs.
+//
+// The DUCET of CLDR 43 is that of Unicode 15, so there is no DUCET overlay for CLDR 43. Run the
+// `variable` binary afterwards (generate.sh does both), since the overlays add variable code
+// points.
+use feruca::{TableInfo, Tailoring};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+const DATA: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../src/data");
+const TEST_DATA: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../test-data");
+
+// Primaries from here up are implicit (Han and unassigned code points), and left alone
+const LIMIT: u32 = 0xFB00;
+// Placeholder primaries for the characters that had primaries of their own
+const PLACEHOLDER: u32 = 0xE000;
+// The code points below this one are stored in the `LOW_*` arrays, apart from L and l
+const LOW: u32 = 0xB7;
+
+// Quotation marks, which had primaries of their own in the DUCET before Unicode 15.1, and in the
+// CLDR root before CLDR 43
+const QUOTES: [u32; 14] = [
+    0x2018, 0x2019, 0x201A, 0x201B, 0x201C, 0x201D, 0x201E, 0x201F, 0x2E42, 0x301D, 0x301E, 0x301F,
+    0x05F3, 0x05F4,
+];
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CollationTable {
+    pub page_index: Vec<u16>,
+    pub entries: Vec<u64>,
+    pub contraction_meta: Vec<ContractionMeta>,
+    pub edges: Vec<ContractionEdge>,
+    pub weights: Vec<u32>,
+    pub prefixes: Vec<PrefixMapping>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PrefixMapping {
+    pub code_point: u32,
+    pub prefix: Vec<u32>,
+    pub weight_start: u32,
+    pub weight_len: u16,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ContractionMeta {
+    pub first_edge: u32,
+    pub edge_len: u16,
+    pub max_len: u8,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ContractionEdge {
+    pub code_point: u32,
+    pub next_first_edge: u32,
+    pub weight_start: u32,
+    pub next_edge_len: u16,
+    pub weight_len: u16,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Node {
+    pub cp: u32,
+    pub row: Option<Vec<u32>>,
+    pub kids: Vec<Node>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Ent {
+    Missing,
+    Simple(Vec<u32>),
+    Contr(Vec<u32>, Vec<Node>),
+}
+
+impl Ent {
+    fn row(&self) -> Option<&[u32]> {
+        match self {
+            Ent::Missing => None,
+            Ent::Simple(r) | Ent::Contr(r, _) => Some(r),
+        }
+    }
+
+    fn map(&self, f: &dyn Fn(u32) -> u32) -> Ent {
+        let row = |r: &[u32]| r.iter().map(|&w| f(w)).collect();
+        match self {
+            Ent::Missing => Ent::Missing,
+            Ent::Simple(r) => Ent::Simple(row(r)),
+            Ent::Contr(r, ks) => Ent::Contr(row(r), ks.iter().map(|n| map_node(n, f)).collect()),
+        }
+    }
+}
+
+fn map_node(n: &Node, f: &dyn Fn(u32) -> u32) -> Node {
+    Node {
+        cp: n.cp,
+        row: n.row.as_ref().map(|r| r.iter().map(|&w| f(w)).collect()),
+        kids: n.kids.iter().map(|k| map_node(k, f)).collect(),
+    }
+}
+
+fn pr(w: u32) -> u32 {
+    w >> 16
+}
+
+// A weight whose primary the reordering may move: not ignorable, and not implicit
+fn in_domain(w: u32) -> bool {
+    w & 0x1FF != 0 && pr(w) > 1 && pr(w) < LIMIT
+}
+
+fn load(name: &str) -> CollationTable {
+    postcard::from_bytes(&std::fs::read(format!("{DATA}/{name}")).unwrap()).unwrap()
+}
+
+fn kids(t: &CollationTable, first: u32, len: u16) -> Vec<Node> {
+    let weights = |start: u32, len: u16| t.weights[start as usize..][..len as usize].to_vec();
+    t.edges[first as usize..][..len as usize]
+        .iter()
+        .map(|e| Node {
+            cp: e.code_point,
+            row: (e.weight_len > 0).then(|| weights(e.weight_start, e.weight_len)),
+            kids: kids(t, e.next_first_edge, e.next_edge_len),
+        })
+        .collect()
+}
+
+fn entry(t: &CollationTable, cp: u32) -> Ent {
+    let e = t.entries[((t.page_index[(cp >> 8) as usize] as usize) << 8) + (cp & 0xFF) as usize];
+    let len = ((e >> 2) & 0xFFFF) as usize;
+    let start = ((e >> 18) & 0xFFFF_FFFF) as usize;
+    let row = || t.weights[start..start + len].to_vec();
+    match e & 3 {
+        0 => Ent::Missing,
+        1 => Ent::Simple(row()),
+        2 => {
+            let m = &t.contraction_meta[(e >> 50) as usize];
+            Ent::Contr(row(), kids(t, m.first_edge, m.edge_len))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn depth(ns: &[Node]) -> u8 {
+    ns.iter().map(|n| 1 + depth(&n.kids)).max().unwrap_or(0)
+}
+
+fn build_overlay(changes: &BTreeMap<u32, Ent>) -> CollationTable {
+    let mut t = CollationTable {
+        page_index: vec![u16::MAX; 0x1100],
+        entries: vec![],
+        contraction_meta: vec![],
+        edges: vec![],
+        weights: vec![],
+        prefixes: vec![],
+    };
+    for (&cp, e) in changes {
+        let pg = (cp >> 8) as usize;
+        if t.page_index[pg] == u16::MAX {
+            t.page_index[pg] = (t.entries.len() / 256) as u16;
+            t.entries.extend(std::iter::repeat_n(3u64, 256));
+        }
+        let idx = ((t.page_index[pg] as usize) << 8) + (cp & 0xFF) as usize;
+        t.entries[idx] = match e {
+            Ent::Missing => 0,
+            Ent::Simple(r) => {
+                let s = t.weights.len() as u64;
+                t.weights.extend(r);
+                1 | ((r.len() as u64) << 2) | (s << 18)
+            }
+            Ent::Contr(r, ks) => {
+                let s = t.weights.len() as u64;
+                t.weights.extend(r);
+                let first = push_kids(&mut t, ks);
+                let mi = t.contraction_meta.len() as u64;
+                t.contraction_meta.push(ContractionMeta {
+                    first_edge: first,
+                    edge_len: ks.len() as u16,
+                    max_len: 1 + depth(ks),
+                });
+                2 | ((r.len() as u64) << 2) | (s << 18) | (mi << 50)
+            }
+        };
+    }
+    t
+}
+
+fn push_kids(t: &mut CollationTable, ks: &[Node]) -> u32 {
+    let first = t.edges.len();
+    for n in ks {
+        t.edges.push(ContractionEdge {
+            code_point: n.cp,
+            next_first_edge: 0,
+            weight_start: 0,
+            next_edge_len: 0,
+            weight_len: 0,
+        });
+    }
+    for (i, n) in ks.iter().enumerate() {
+        if let Some(r) = &n.row {
+            t.edges[first + i].weight_start = t.weights.len() as u32;
+            t.edges[first + i].weight_len = r.len() as u16;
+            t.weights.extend(r);
+        }
+        if !n.kids.is_empty() {
+            let f = push_kids(t, &n.kids);
+            t.edges[first + i].next_first_edge = f;
+            t.edges[first + i].next_edge_len = n.kids.len() as u16;
+        }
+    }
+    first as u32
+}
+
+// All the code points in a test file, and the single code points in the order it lists them
+fn read_test(path: &str) -> (BTreeSet<u32>, Vec<u32>) {
+    let mut all = BTreeSet::new();
+    let mut singles = vec![];
+    for l in std::fs::read_to_string(path).unwrap().lines() {
+        if l.is_empty() || l.starts_with('#') {
+            continue;
+        }
+        let cps: Vec<u32> = l
+            .split(' ')
+            .map(|x| u32::from_str_radix(x, 16).unwrap())
+            .collect();
+        if cps.len() == 2 && cps[1] == 0x21 {
+            singles.push(cps[0]);
+        }
+        all.extend(cps);
+    }
+    (all, singles)
+}
+
+// The low code points' weights, as single packed CEs (0 for none)
+fn low_weights(tailoring: Tailoring) -> Vec<u32> {
+    let info = TableInfo::new(tailoring);
+    (0..LOW)
+        .map(|cp| match info.weights(&[cp]).unwrap().as_slice() {
+            [] => 0,
+            [e] => {
+                ((e.primary as u32) << 16)
+                    | ((e.variable as u32) << 15)
+                    | ((e.tertiary as u32) << 9)
+                    | e.secondary as u32
+            }
+            _ => panic!("U+{cp:04X} has more than one CE"),
+        })
+        .collect()
+}
+
+fn is_low(cp: u32) -> bool {
+    cp < LOW && cp != 0x4C && cp != 0x6C
+}
+
+fn implicit_unassigned(cp: u32) -> Vec<u32> {
+    vec![
+        ((0xFBC0 + (cp >> 15)) << 16) | 0x20 | (2 << 9),
+        ((cp & 0x7FFF) | 0x8000) << 16,
+    ]
+}
+
+// Characters with primaries of their own (to be placed as the test file has them), and rows
+// given directly, in a version of a table
+fn spec(version: &str, cldr: bool) -> (Vec<u32>, Vec<(u32, Vec<u32>)>) {
+    let mut own = vec![];
+    if matches!(version, "14" | "15") {
+        own.extend(QUOTES);
+    }
+    if version != "cldr-44" {
+        // PESETA SIGN and OLD SOUTH ARABIAN NUMERIC INDICATOR
+        own.extend([0x20A7, 0x10A7F]);
+    }
+    if cldr {
+        // RUPEE SIGN and RIAL SIGN
+        own.extend([0x20A8, 0xFDFC]);
+    }
+    let mut rows = vec![];
+    if version == "14" {
+        rows.push((0x0F82, vec![0]));
+        rows.push((0x0F83, vec![0]));
+        rows.push((0x101FD, vec![0x11E | (2 << 9)]));
+    }
+    (own, rows)
+}
+
+// Weighted longest chain of primaries (in the new order) that can keep their current values:
+// those at positions i < j with p_i - i <= p_j - j leave room for everything between them
+fn keep(order: &[u32], use_count: &HashMap<u32, u64>) -> Vec<bool> {
+    const SIZE: usize = 0x10000;
+    let n = order.len();
+    // A Fenwick tree over p - i, for the best chain ending at or below each value
+    let mut tree: Vec<(u64, usize)> = vec![(0, usize::MAX); SIZE + 1];
+    let mut prev = vec![usize::MAX; n];
+    let mut best = (0u64, usize::MAX);
+    for (i, &p) in order.iter().enumerate() {
+        if p >= PLACEHOLDER {
+            continue;
+        }
+        let k = p as i64 - i as i64;
+        if k < 2 || k > PLACEHOLDER as i64 - n as i64 {
+            continue;
+        }
+        let k = k as usize;
+        let mut q = (0u64, usize::MAX);
+        let mut x = k;
+        while x > 0 {
+            if tree[x].0 > q.0 {
+                q = tree[x];
+            }
+            x &= x - 1;
+        }
+        prev[i] = q.1;
+        let val = (q.0 + use_count.get(&p).copied().unwrap_or(1), i);
+        if val.0 > best.0 {
+            best = val;
+        }
+        let mut x = k;
+        while x <= SIZE {
+            if val.0 > tree[x].0 {
+                tree[x] = val;
+            }
+            x += x & x.wrapping_neg();
+        }
+    }
+    let mut keep = vec![false; n];
+    let mut j = best.1;
+    loop {
+        keep[j] = true;
+        if prev[j] == usize::MAX {
+            break;
+        }
+        j = prev[j];
+    }
+    keep
+}
+
+fn main() {
+    let out = std::env::args().nth(1).unwrap_or_else(|| DATA.to_string());
+
+    // Han ideographs assigned since each version (which all have implicit weights now)
+    let unassigned_han: HashMap<&str, Vec<(u32, u32)>> = [
+        (
+            "14",
+            vec![(0x2B739, 0x2B739), (0x2EBF0, 0x2EE5D), (0x31350, 0x323AF)],
+        ),
+        ("15", vec![(0x2EBF0, 0x2EE5D)]),
+        ("cldr-43", vec![(0x2EBF0, 0x2EE5D)]),
+        ("cldr-44", vec![]),
+    ]
+    .into_iter()
+    .collect();
+
+    let versions = [
+        ("14", "unicode_14"),
+        ("15", "unicode_15"),
+        ("cldr-43", "cldr_43"),
+        ("cldr-44", "cldr_44"),
+    ];
+
+    for (name, test, low_name, tailoring, first_non_variable) in [
+        (
+            "ducet",
+            "CollationTest_NON_IGNORABLE_SHORT.txt",
+            "LOW_DUCET",
+            Tailoring::Ducet,
+            0x212A,
+        ),
+        (
+            "cldr_root",
+            "CollationTest_CLDR_NON_IGNORABLE_SHORT.txt",
+            "LOW_CLDR",
+            Tailoring::default(),
+            0x04E0,
+        ),
+    ] {
+        let t = load(name);
+        let cldr = name == "cldr_root";
+        let base_low = low_weights(tailoring);
+        let (current, _) = read_test(&format!("{TEST_DATA}/cldr-46_1/{test}"));
+
+        // The current entries, with the low code points as single rows
+        let get = |cp: u32| -> Ent {
+            if is_low(cp) {
+                let w = base_low[cp as usize];
+                Ent::Simple(if w == 0 { vec![] } else { vec![w] })
+            } else {
+                entry(&t, cp)
+            }
+        };
+
+        // Every primary that may move, and how often each is used (those of the low code points
+        // above all, so that they keep their values where they can)
+        let mut prims: BTreeSet<u32> = t
+            .weights
+            .iter()
+            .filter(|&&w| in_domain(w))
+            .map(|&w| pr(w))
+            .collect();
+        prims.extend(base_low.iter().filter(|&&w| in_domain(w)).map(|&w| pr(w)));
+        let mut use_count: HashMap<u32, u64> = HashMap::new();
+        for &w in &t.weights {
+            if in_domain(w) {
+                *use_count.entry(pr(w)).or_default() += 1;
+            }
+        }
+        for &w in &base_low {
+            if in_domain(w) {
+                *use_count.entry(pr(w)).or_default() += 100_000;
+            }
+        }
+        // The variable flag is set on the primaries below the first non-variable one
+        for &w in t.weights.iter().chain(&base_low) {
+            if in_domain(w) {
+                assert_eq!((w >> 15) & 1 == 1, pr(w) < first_non_variable, "{w:X}");
+            }
+        }
+
+        for (version, dir) in versions {
+            if !cldr && version == "cldr-43" {
+                continue;
+            }
+            let (own, rows) = spec(version, cldr);
+            let mut over: HashMap<u32, Ent> = HashMap::new();
+            for (i, &cp) in own.iter().enumerate() {
+                over.insert(
+                    cp,
+                    Ent::Simple(vec![((PLACEHOLDER + i as u32) << 16) | 0x20 | (2 << 9)]),
+                );
+            }
+            for (cp, r) in rows {
+                over.insert(cp, Ent::Simple(r));
+            }
+            let working = |cp: u32| over.get(&cp).cloned().unwrap_or_else(|| get(cp));
+
+            // Where the test file has each primary (the median position of its code points)
+            let (old_all, old) = read_test(&format!("{TEST_DATA}/{version}/{test}"));
+            let mut samples: HashMap<u32, Vec<usize>> = HashMap::new();
+            for (i, &cp) in old.iter().enumerate() {
+                let Some(&w) = working(cp)
+                    .row()
+                    .and_then(|r| r.iter().find(|&&w| pr(w) != 0))
+                else {
+                    continue;
+                };
+                if pr(w) < LIMIT {
+                    samples.entry(pr(w)).or_default().push(i);
+                }
+            }
+            let rank = |p: u32| {
+                samples.get(&p).map(|v| {
+                    let mut v = v.clone();
+                    v.sort_unstable();
+                    v[v.len() / 2]
+                })
+            };
+
+            // The new order: primaries the test doesn't use stay right after the one before them
+            let mut keys: Vec<(usize, u8, u32)> = vec![];
+            let mut last = 0;
+            for &p in &prims {
+                match rank(p) {
+                    Some(r) => {
+                        last = r;
+                        keys.push((r, 0, p));
+                    }
+                    None => keys.push((last, 1, p)),
+                }
+            }
+            for i in 0..own.len() as u32 {
+                let p = PLACEHOLDER + i;
+                keys.push((rank(p).expect("character not in the test file"), 0, p));
+            }
+            keys.sort_unstable();
+            let order: Vec<u32> = keys.iter().map(|k| k.2).collect();
+
+            let keep = keep(&order, &use_count);
+            let mut assigned: HashMap<u32, u32> = HashMap::new();
+            let mut current_value = 1;
+            let mut moved = 0;
+            for (i, &p) in order.iter().enumerate() {
+                let a = if keep[i] {
+                    p
+                } else {
+                    moved += 1;
+                    current_value + 1
+                };
+                assert!(a > current_value);
+                current_value = a;
+                assigned.insert(p, a);
+            }
+            assert!(current_value < PLACEHOLDER);
+            let non_variable = assigned[&first_non_variable];
+            let f = |w: u32| {
+                if !in_domain(w) {
+                    return w;
+                }
+                let a = assigned[&pr(w)];
+                (a << 16) | (((a < non_variable) as u32) << 15) | (w & 0x7FFF)
+            };
+
+            let mut changes: BTreeMap<u32, Ent> = BTreeMap::new();
+            let mut low = base_low.clone();
+            for cp in 0..0x11_0000 {
+                let after = working(cp).map(&f);
+                if is_low(cp) {
+                    let Ent::Simple(r) = &after else {
+                        unreachable!()
+                    };
+                    assert!(r.len() <= 1);
+                    low[cp as usize] = r.first().copied().unwrap_or(0);
+                } else if after != get(cp) {
+                    changes.insert(cp, after);
+                }
+            }
+            for cp in 0..0x11_0000 {
+                if entry(&t, cp) != Ent::Missing && current.contains(&cp) && !old_all.contains(&cp)
+                {
+                    changes.insert(cp, Ent::Missing);
+                }
+            }
+            for &(a, b) in &unassigned_han[version] {
+                for cp in a..=b {
+                    changes.insert(cp, Ent::Simple(implicit_unassigned(cp)));
+                }
+            }
+
+            let bytes = postcard::to_allocvec(&build_overlay(&changes)).unwrap();
+            std::fs::create_dir_all(format!("{out}/legacy/{dir}")).unwrap();
+            std::fs::write(format!("{out}/legacy/{dir}/{name}"), &bytes).unwrap();
+            eprintln!(
+                "{dir}/{name}: {moved} primaries moved, {} entries, {} bytes",
+                changes.len(),
+                bytes.len()
+            );
+
+            if low != base_low {
+                let values: Vec<String> = low
+                    .iter()
+                    .enumerate()
+                    .map(|(cp, w)| match cp {
+                        0x4C | 0x6C => "LOW_DEFER".to_string(),
+                        _ => w.to_string(),
+                    })
+                    .collect();
+                println!(
+                    "pub const {low_name}_{}: [u32; 183] = [{}];",
+                    dir.to_uppercase(),
+                    values.join(", ")
+                );
+            }
+        }
+    }
+}
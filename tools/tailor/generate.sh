@@ -0,0 +1,95 @@
+#!/bin/sh
+# Regenerate the locale tailorings in src/data/tailoring and the Han orders in src/data/han.
+#
+# The rules come from Perl's Unicode::Collate 1.31 (the Locale/*.pl files and the CJK/*.pm
+# modules), apart from Pashto, which Unicode::Collate doesn't cover (see rules/ps.pl). Its locale
+# files transcribe the rules of CLDR 22.1 to 30, with weights from the DUCET of Unicode 13
+# (allkeys.txt), which the tool maps onto our CLDR 46.1 root. Pass the Unicode/Collate directory
+# of a Perl installation as the first argument, or leave it out to ask Perl where it is. An output
+# directory other than src/data can be given as the second argument.
+#
+# The tables for the Arabic script (arabic_script and arabic_interleaved), like the root tables,
+# come from feruca-mapper instead.
+#
+# data/nfd.txt lists the canonical decompositions (other than those of Hangul syllables), for the
+# canonical closure of each tailoring. data/emoji_order.txt lists the emoji sequences in the order
+# of Unicode's emoji-test.txt (as taken from the data of the `emojis` crate, version 0.6.4).
+
+set -eu
+
+cd "$(dirname "$0")"
+
+FIND='for (@INC) { -e "$_/Unicode/Collate/allkeys.txt" and print "$_/Unicode/Collate" and last }'
+UNICODE_COLLATE=${1:-$(perl -e "$FIND")}
+export UNICODE_COLLATE
+OUT=${2:-../../src/data}
+L=$UNICODE_COLLATE/Locale
+CJK=$UNICODE_COLLATE/CJK
+T=$OUT/tailoring
+H=$OUT/han
+
+cargo build --release --quiet
+tailor() {
+    ./target/release/tailor "$@"
+}
+
+mkdir -p "$T" "$H"
+
+# Latin-script languages
+tailor "$L/de_phone.pl" "$T/german_phonebook"
+tailor "$L/sv_refo.pl" "$T/swedish"
+tailor "$L/fi_phone.pl" "$T/finnish"
+tailor "$L/da.pl" "$T/danish"
+tailor "$L/nb.pl" "$T/norwegian"
+tailor "$L/es.pl" "$T/spanish"
+tailor "$L/es_trad.pl" "$T/spanish_traditional"
+tailor "$L/tr.pl" "$T/turkish"
+tailor "$L/az.pl" "$T/azerbaijani"
+tailor "$L/pl.pl" "$T/polish"
+tailor "$L/cs.pl" "$T/czech"
+tailor "$L/sk.pl" "$T/slovak"
+tailor "$L/lt.pl" "$T/lithuanian"
+tailor "$L/vi.pl" "$T/vietnamese"
+tailor "$L/hu.pl" "$T/hungarian"
+
+# Scripts moved before Latin: Arabic, Greek, Cyrillic, Hebrew, Devanagari, Bengali, Tamil, Thai,
+# Lao. In `--reorder LO-HI:NEWLO`, LO and HI are the root primaries of the script's first and last
+# letters (e.g., 2E68 for U+0621 ARABIC LETTER HAMZA, and 2F56 for U+088E), and NEWLO is where the
+# first of them goes. The root leaves the primaries from 2380 up to Latin (whose first, for "a", is
+# 2780) unused, so most scripts are put at the end of that gap, finishing at 277F. Arabic starts at
+# 2468 instead, where the ArabicScript tailoring (from feruca-mapper) has it.
+tailor "$L/fa.pl" "$T/persian" --reorder 2e68-2f56:2468
+tailor "$L/ur.pl" "$T/urdu" --reorder 2e68-2f56:2468
+tailor rules/ps.pl "$T/pashto" --reorder 2e68-2f56:2468
+tailor - "$T/greek" --reorder 2b20-2b4c:2753
+tailor - "$T/cyrillic_script" --reorder 2b89-2d49:25bf
+tailor "$L/uk.pl" "$T/ukrainian" --reorder 2b89-2d49:25bf
+tailor "$L/sr.pl" "$T/serbian" --suppress 418,438 --reorder 2b89-2d49:25bf
+tailor "$L/he.pl" "$T/hebrew" --reorder 2e22-2e37:276a
+tailor "$L/hi.pl" "$T/hindi" --reorder 3202-3267:271a
+tailor "$L/bn.pl" "$T/bengali" --reorder 3268-32a9:273e
+tailor "$L/ta.pl" "$T/tamil" --reorder 336b-339c:274e
+tailor "$L/th.pl" "$T/thai" --reorder 3a98-3ad7:2740
+tailor - "$T/lao" --reorder 3ad8-3b16:2741
+
+# Han orders (with Bopomofo moved before Latin for Chinese, just after the Han lead primary 2700,
+# and Hangul for Korean, as above)
+tailor "$L/zh_pin.pl" "$T/chinese_pinyin" --han "$CJK/Pinyin.pm" "$H/pinyin" 2700 \
+    --reorder 4d36-4d6d:2701
+tailor "$L/zh_strk.pl" "$T/chinese_stroke" --han "$CJK/Stroke.pm" "$H/stroke" 2700 \
+    --reorder 4d36-4d6d:2701
+tailor "$L/zh_zhu.pl" "$T/chinese_zhuyin" --han "$CJK/Zhuyin.pm" "$H/zhuyin" 2700 \
+    --reorder 4d36-4d6d:2701
+tailor "$L/ja.pl" "$T/japanese" --han "$CJK/JISX0208.pm" "$H/japanese" 7F00 \
+    --prefix 30FC,FF70,309D,309E,30FD,30FE --kana
+tailor - "$T/korean" --korean "$CJK/Korean.pm" --reorder 4a7f-4bb2:264c
+
+# Emoji, in the order of data/emoji_order.txt
+tailor - "$T/emoji" --emoji data/emoji_order.txt
+
+# Check that each table (as now built into feruca) collates precomposed characters as their NFD,
+# and update the list of variable code points (src/data/variable) to cover the new tables
+if [ "$OUT" = ../../src/data ]; then
+    cargo run --release --quiet --bin check
+    (cd ../legacy && cargo run --release --quiet --bin variable)
+fi
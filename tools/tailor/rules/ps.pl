@@ -0,0 +1,52 @@
+# Pashto, which Unicode::Collate::Locale doesn't cover, in the format of its locale files: the
+# letters in the Pashto alphabetical order, as trailing weights after a common primary
++{
+   locale_version => 1.31,
+   entry => <<'ENTRY', # for DUCET v13.0.0
+0628      ; [.2673.0020.0002][.FFC0.0000.0000] # ARABIC LETTER BEH
+067E      ; [.2673.0020.0002][.FFC1.0000.0000] # ARABIC LETTER PEH
+062A      ; [.2673.0020.0002][.FFC2.0000.0000] # ARABIC LETTER TEH
+067C      ; [.2673.0020.0002][.FFC3.0000.0000] # ARABIC LETTER TEH WITH RING
+062B      ; [.2673.0020.0002][.FFC4.0000.0000] # ARABIC LETTER THEH
+062C      ; [.2673.0020.0002][.FFC5.0000.0000] # ARABIC LETTER JEEM
+0681      ; [.2673.0020.0002][.FFC6.0000.0000] # ARABIC LETTER HAH WITH HAMZA ABOVE
+0686      ; [.2673.0020.0002][.FFC7.0000.0000] # ARABIC LETTER TCHEH
+0685      ; [.2673.0020.0002][.FFC8.0000.0000] # ARABIC LETTER HAH WITH THREE DOTS ABOVE
+062D      ; [.2673.0020.0002][.FFC9.0000.0000] # ARABIC LETTER HAH
+062E      ; [.2673.0020.0002][.FFCA.0000.0000] # ARABIC LETTER KHAH
+062F      ; [.2673.0020.0002][.FFCB.0000.0000] # ARABIC LETTER DAL
+0689      ; [.2673.0020.0002][.FFCC.0000.0000] # ARABIC LETTER DAL WITH RING
+0630      ; [.2673.0020.0002][.FFCD.0000.0000] # ARABIC LETTER THAL
+0631      ; [.2673.0020.0002][.FFCE.0000.0000] # ARABIC LETTER REH
+0693      ; [.2673.0020.0002][.FFCF.0000.0000] # ARABIC LETTER REH WITH RING
+0632      ; [.2673.0020.0002][.FFD0.0000.0000] # ARABIC LETTER ZAIN
+0698      ; [.2673.0020.0002][.FFD1.0000.0000] # ARABIC LETTER JEH
+0696      ; [.2673.0020.0002][.FFD2.0000.0000] # ARABIC LETTER REH WITH DOT BELOW AND DOT ABOVE
+0633      ; [.2673.0020.0002][.FFD3.0000.0000] # ARABIC LETTER SEEN
+0634      ; [.2673.0020.0002][.FFD4.0000.0000] # ARABIC LETTER SHEEN
+069A      ; [.2673.0020.0002][.FFD5.0000.0000] # ARABIC LETTER SEEN WITH DOT BELOW AND DOT ABOVE
+0635      ; [.2673.0020.0002][.FFD6.0000.0000] # ARABIC LETTER SAD
+0636      ; [.2673.0020.0002][.FFD7.0000.0000] # ARABIC LETTER DAD
+0637      ; [.2673.0020.0002][.FFD8.0000.0000] # ARABIC LETTER TAH
+0638      ; [.2673.0020.0002][.FFD9.0000.0000] # ARABIC LETTER ZAH
+0639      ; [.2673.0020.0002][.FFDA.0000.0000] # ARABIC LETTER AIN
+063A      ; [.2673.0020.0002][.FFDB.0000.0000] # ARABIC LETTER GHAIN
+0641      ; [.2673.0020.0002][.FFDC.0000.0000] # ARABIC LETTER FEH
+0642      ; [.2673.0020.0002][.FFDD.0000.0000] # ARABIC LETTER QAF
+06A9      ; [.2673.0020.0002][.FFDE.0000.0000] # ARABIC LETTER KEHEH
+06AB      ; [.2673.0020.0002][.FFDF.0000.0000] # ARABIC LETTER KAF WITH RING
+0644      ; [.2673.0020.0002][.FFE0.0000.0000] # ARABIC LETTER LAM
+0645      ; [.2673.0020.0002][.FFE1.0000.0000] # ARABIC LETTER MEEM
+0646      ; [.2673.0020.0002][.FFE2.0000.0000] # ARABIC LETTER NOON
+06BC      ; [.2673.0020.0002][.FFE3.0000.0000] # ARABIC LETTER NOON WITH RING
+0648      ; [.2673.0020.0002][.FFE4.0000.0000] # ARABIC LETTER WAW
+0647      ; [.2673.0020.0002][.FFE5.0000.0000] # ARABIC LETTER HEH
+064A      ; [.2673.0020.0002][.FFE6.0000.0000] # ARABIC LETTER YEH
+06D0      ; [.2673.0020.0002][.FFE7.0000.0000] # ARABIC LETTER E
+06CC      ; [.2673.0020.0002][.FFE8.0000.0000] # ARABIC LETTER FARSI YEH
+06CD      ; [.2673.0020.0002][.FFE9.0000.0000] # ARABIC LETTER YEH WITH TAIL
+0626      ; [.2673.0020.0002][.FFEA.0000.0000] # ARABIC LETTER YEH WITH HAMZA ABOVE
+064A 0654 ; [.2673.0020.0002][.FFEA.0000.0000] # ARABIC LETTER YEH WITH HAMZA ABOVE
+066E      ; [.2673.0020.0002][.FFEB.0000.0000] # ARABIC LETTER DOTLESS BEH
+ENTRY
+};
@@ -0,0 +1,153 @@
+// Check the canonical closure of the built-in tables: every string below must collate equal to its
+// NFD. The strings are each precomposed character in data/nfd.txt on its own, followed by a
+// combining mark that some contraction of the table continues with (or a precomposed one that
+// decomposes to such a mark), and after the start of a contraction whose next code point begins
+// its decomposition (e.g., "Aå" in Danish, where "aa" is a contraction).
+//
+// feruca embeds its tables when it is built, so this checks what is in src/data; generate.sh runs
+// it after rebuilding them. The exit status is non-zero if any string fails.
+use feruca::{Collator, Locale, TableInfo, Tailoring};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use unicode_canonical_combining_class::get_canonical_combining_class_u32 as get_ccc;
+
+const NFD: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/nfd.txt");
+
+const LOCALES: [Locale; 41] = [
+    Locale::Root,
+    Locale::ArabicScript,
+    Locale::ArabicInterleaved,
+    Locale::German,
+    Locale::GermanPhonebook,
+    Locale::Swedish,
+    Locale::Finnish,
+    Locale::Danish,
+    Locale::NorwegianBokmal,
+    Locale::NorwegianNynorsk,
+    Locale::Spanish,
+    Locale::SpanishTraditional,
+    Locale::Turkish,
+    Locale::Azerbaijani,
+    Locale::Polish,
+    Locale::Czech,
+    Locale::Slovak,
+    Locale::Persian,
+    Locale::Urdu,
+    Locale::Pashto,
+    Locale::Lithuanian,
+    Locale::Vietnamese,
+    Locale::ChinesePinyin,
+    Locale::ChineseStroke,
+    Locale::ChineseZhuyin,
+    Locale::Japanese,
+    Locale::JapaneseHiraganaQuaternary,
+    Locale::Korean,
+    Locale::Hindi,
+    Locale::Bengali,
+    Locale::Tamil,
+    Locale::Hungarian,
+    Locale::Emoji,
+    Locale::Greek,
+    Locale::Russian,
+    Locale::Bulgarian,
+    Locale::Ukrainian,
+    Locale::Serbian,
+    Locale::Hebrew,
+    Locale::Thai,
+    Locale::Lao,
+];
+
+fn nfd(decomp: &BTreeMap<u32, Vec<u32>>, s: &[u32]) -> Vec<u32> {
+    let mut out: Vec<u32> = s
+        .iter()
+        .flat_map(|cp| decomp.get(cp).cloned().unwrap_or_else(|| vec![*cp]))
+        .collect();
+    // Canonical ordering: a stable sort of each run of non-starters by combining class
+    let mut i = 0;
+    while i < out.len() {
+        let j = (i..out.len())
+            .find(|&j| get_ccc(out[j]) as u8 == 0)
+            .unwrap_or(out.len());
+        out[i..j].sort_by_key(|&c| get_ccc(c) as u8);
+        i = j + 1;
+    }
+    out
+}
+
+fn text(s: &[u32]) -> String {
+    s.iter().map(|&c| char::from_u32(c).unwrap()).collect()
+}
+
+fn main() {
+    let mut decomp = BTreeMap::new();
+    for l in std::fs::read_to_string(NFD).unwrap().lines() {
+        let (cp, d) = l.split_once(';').unwrap();
+        let d: Vec<u32> = d
+            .split_whitespace()
+            .map(|x| u32::from_str_radix(x, 16).unwrap())
+            .collect();
+        decomp.insert(u32::from_str_radix(cp, 16).unwrap(), d);
+    }
+
+    let mut failed = 0;
+    for locale in LOCALES {
+        let info = TableInfo::new(Tailoring::Cldr(locale));
+        let contractions: Vec<Vec<u32>> = info.contractions().collect();
+
+        let mut strings: BTreeSet<Vec<u32>> = BTreeSet::new();
+        let mut marks: BTreeSet<u32> = contractions
+            .iter()
+            .flat_map(|seq| seq[1..].iter().copied())
+            .filter(|&c| get_ccc(c) as u8 != 0)
+            .collect();
+        let composite: Vec<u32> = decomp
+            .iter()
+            .filter(|(_, d)| marks.contains(&d[0]))
+            .map(|(&cp, _)| cp)
+            .collect();
+        marks.extend(composite);
+        for &cp in decomp.keys() {
+            strings.insert(vec![cp]);
+            for &m in &marks {
+                strings.insert(vec![cp, m]);
+            }
+        }
+        for seq in &contractions {
+            for k in 1..seq.len() {
+                for (&cp, d) in &decomp {
+                    if d[0] == seq[k] {
+                        let mut s = seq[..k].to_vec();
+                        s.push(cp);
+                        strings.insert(s);
+                    }
+                }
+            }
+        }
+
+        let mut bad = vec![];
+        for shifting in [false, true] {
+            let mut collator = Collator::new(Tailoring::Cldr(locale), shifting, false);
+            for s in &strings {
+                let d = nfd(&decomp, s);
+                if collator.collate(&text(s), &text(&d)) != Ordering::Equal {
+                    bad.push((shifting, s));
+                }
+            }
+        }
+        if !bad.is_empty() {
+            println!(
+                "{locale:?}: {} of {} strings fail",
+                bad.len(),
+                strings.len()
+            );
+            for (shifting, s) in bad.iter().take(8) {
+                println!("    {s:04X?} (shifting: {shifting})");
+            }
+            failed += bad.len();
+        }
+    }
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,1206 @@
+// Build a locale overlay (on the CLDR root table) from a Unicode::Collate::Locale .pl file.
+// usage: tailor <locale.pl | -> <out> [options]
+//   --suppress XXXX,YYYY     drop root contractions starting with these code points
+//   --reorder LO-HI:NEWLO    move root primaries LO..=HI so they start at NEWLO (hex)
+//   --han MODULE.pm OUT LEAD write the module's Han order to OUT as a rank table; locale primaries
+//                            from 8000 up become [LEAD][rank] pairs
+//   --prefix XXXX,YYYY       turn contractions ending in these code points into prefix mappings
+//   --kana                   set tertiary bit 0x20 on CEs whose fourth weight is 0002 (katakana)
+//   --korean MODULE.pm       give the module's Hanja the weights of their Hangul readings, with
+//                            fresh secondaries (above the root's) on the first jamo
+//   --emoji FILE             give each emoji sequence listed in FILE a primary of its own, in order
+//
+// The DUCET that the locale files are written against is read from `$UNICODE_COLLATE/allkeys.txt`,
+// where UNICODE_COLLATE is the Unicode/Collate directory of the Perl installation. See
+// generate.sh for the options used for each table in src/data.
+use feruca::{CollationElement, Locale, TableInfo, Tailoring};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+const ROOT_TABLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../src/data/cldr_root");
+const NFD: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/nfd.txt");
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CollationTable {
+    pub page_index: Vec<u16>,
+    pub entries: Vec<u64>,
+    pub contraction_meta: Vec<ContractionMeta>,
+    pub edges: Vec<ContractionEdge>,
+    pub weights: Vec<u32>,
+    pub prefixes: Vec<PrefixMapping>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PrefixMapping {
+    pub code_point: u32,
+    pub prefix: Vec<u32>,
+    pub weight_start: u32,
+    pub weight_len: u16,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ContractionMeta {
+    pub first_edge: u32,
+    pub edge_len: u16,
+    pub max_len: u8,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ContractionEdge {
+    pub code_point: u32,
+    pub next_first_edge: u32,
+    pub weight_start: u32,
+    pub next_edge_len: u16,
+    pub weight_len: u16,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Node {
+    pub cp: u32,
+    pub row: Option<Vec<u32>>,
+    pub kids: Vec<Node>,
+}
+
+pub fn pack(variable: bool, p: u32, s: u32, t: u32) -> u32 {
+    (p << 16) | ((variable as u32) << 15) | (t << 9) | s
+}
+pub fn pack_ces(ces: &[CollationElement]) -> Vec<u32> {
+    ces.iter()
+        .map(|e| {
+            pack(
+                e.variable,
+                e.primary as u32,
+                e.secondary as u32,
+                e.tertiary as u32,
+            )
+        })
+        .collect()
+}
+pub fn pr(w: u32) -> u32 {
+    w >> 16
+}
+
+pub fn parse_ces(s: &str) -> Vec<Vec<u32>> {
+    s.split('[')
+        .skip(1)
+        .map(|c| {
+            let c = c.trim_end_matches(|ch| ch != ']').trim_end_matches(']');
+            c.trim_start_matches(['.', '*'])
+                .split(['.', '*'])
+                .map(|x| u32::from_str_radix(x, 16).unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+pub fn parse_seq(s: &str) -> Vec<u32> {
+    s.split_whitespace()
+        .map(|x| u32::from_str_radix(x, 16).unwrap())
+        .collect()
+}
+
+pub fn load_allkeys() -> HashMap<Vec<u32>, Vec<Vec<u32>>> {
+    let mut out = HashMap::new();
+    let dir = std::env::var("UNICODE_COLLATE").expect("UNICODE_COLLATE is not set");
+    for l in std::fs::read_to_string(format!("{dir}/allkeys.txt"))
+        .unwrap()
+        .lines()
+    {
+        if l.is_empty() || l.starts_with('#') || l.starts_with('@') {
+            continue;
+        }
+        let (k, v) = l.split_once(';').unwrap();
+        let v = v.split('#').next().unwrap();
+        out.insert(parse_seq(k), parse_ces(v));
+    }
+    out
+}
+
+pub struct Root {
+    pub info: TableInfo,
+    pub raw: CollationTable,
+    pub used: BTreeSet<u32>,
+    pub max_variable: u32,
+    pub code_points: BTreeSet<u32>,
+    // The root's contractions, as a trie for each code point that starts any
+    pub contractions: BTreeMap<u32, Vec<Node>>,
+}
+
+impl Root {
+    pub fn load() -> Self {
+        let raw: CollationTable =
+            postcard::from_bytes(&std::fs::read(ROOT_TABLE).unwrap()).unwrap();
+        let info = TableInfo::new(Tailoring::Cldr(Locale::Root));
+        let mut used = BTreeSet::new();
+        let mut max_variable = 0;
+        let code_points: BTreeSet<u32> = info.code_points().collect();
+        for &cp in &code_points {
+            for ce in info.weights(&[cp]).unwrap() {
+                used.insert(ce.primary as u32);
+                if ce.variable {
+                    max_variable = max_variable.max(ce.primary as u32);
+                }
+            }
+        }
+        let mut contractions: BTreeMap<u32, Vec<Node>> = BTreeMap::new();
+        for seq in info.contractions() {
+            let ces = info.weights(&seq).unwrap();
+            for ce in &ces {
+                used.insert(ce.primary as u32);
+            }
+            insert(
+                contractions.entry(seq[0]).or_default(),
+                &seq[1..],
+                pack_ces(&ces),
+            );
+        }
+        Self {
+            info,
+            raw,
+            used,
+            max_variable,
+            code_points,
+            contractions,
+        }
+    }
+
+    pub fn weights(&self, seq: &[u32]) -> Option<Vec<u32>> {
+        self.info.weights(seq).map(|v| pack_ces(&v))
+    }
+
+    pub fn kids(&self, cp: u32) -> Vec<Node> {
+        self.contractions.get(&cp).cloned().unwrap_or_default()
+    }
+}
+
+pub fn insert(nodes: &mut Vec<Node>, tail: &[u32], row: Vec<u32>) {
+    let i = match nodes.binary_search_by_key(&tail[0], |n| n.cp) {
+        Ok(i) => i,
+        Err(i) => {
+            nodes.insert(
+                i,
+                Node {
+                    cp: tail[0],
+                    row: None,
+                    kids: vec![],
+                },
+            );
+            i
+        }
+    };
+    if tail.len() == 1 {
+        nodes[i].row = Some(row);
+    } else {
+        insert(&mut nodes[i].kids, &tail[1..], row);
+    }
+}
+
+pub fn depth(ns: &[Node]) -> u8 {
+    ns.iter().map(|n| 1 + depth(&n.kids)).max().unwrap_or(0)
+}
+
+#[derive(Clone, Debug)]
+pub enum Ent {
+    Simple(Vec<u32>),
+    Contr(Vec<u32>, Vec<Node>),
+}
+
+pub fn build_overlay(
+    changes: &BTreeMap<u32, Ent>,
+    prefixes: &[(u32, Vec<u32>, Vec<u32>)],
+) -> CollationTable {
+    let mut t = CollationTable {
+        page_index: vec![u16::MAX; 0x1100],
+        entries: vec![],
+        contraction_meta: vec![],
+        edges: vec![],
+        weights: vec![],
+        prefixes: vec![],
+    };
+    let prefixed: BTreeSet<u32> = prefixes.iter().map(|p| p.0).collect();
+    let mut all: BTreeMap<u32, Option<&Ent>> = changes.iter().map(|(k, v)| (*k, Some(v))).collect();
+    for cp in &prefixed {
+        all.entry(*cp).or_insert(None);
+    }
+    for (&cp, e) in &all {
+        let e = e.expect("prefixed code point must have an explicit entry");
+        let pg = (cp >> 8) as usize;
+        if t.page_index[pg] == u16::MAX {
+            t.page_index[pg] = (t.entries.len() / 256) as u16;
+            t.entries.extend(std::iter::repeat_n(3u64, 256));
+        }
+        let idx = ((t.page_index[pg] as usize) << 8) + (cp & 0xff) as usize;
+        let mut v = match e {
+            Ent::Simple(r) => {
+                let s = t.weights.len() as u64;
+                t.weights.extend(r);
+                1 | ((r.len() as u64) << 2) | (s << 18)
+            }
+            Ent::Contr(r, ks) => {
+                let s = t.weights.len() as u64;
+                t.weights.extend(r);
+                let first = push_kids(&mut t, ks);
+                let mi = t.contraction_meta.len() as u64;
+                t.contraction_meta.push(ContractionMeta {
+                    first_edge: first,
+                    edge_len: ks.len() as u16,
+                    max_len: 1 + depth(ks),
+                });
+                2 | ((r.len() as u64) << 2) | (s << 18) | (mi << 50)
+            }
+        };
+        if prefixed.contains(&cp) {
+            v |= 1 << 63;
+        }
+        t.entries[idx] = v;
+    }
+    let mut ps: Vec<_> = prefixes.to_vec();
+    ps.sort_by(|a, b| {
+        a.0.cmp(&b.0)
+            .then(b.1.len().cmp(&a.1.len()))
+            .then(a.1.cmp(&b.1))
+    });
+    for (cp, prefix, row) in ps {
+        let s = t.weights.len() as u32;
+        t.weights.extend(&row);
+        t.prefixes.push(PrefixMapping {
+            code_point: cp,
+            prefix,
+            weight_start: s,
+            weight_len: row.len() as u16,
+        });
+    }
+    t
+}
+
+fn push_kids(t: &mut CollationTable, ks: &[Node]) -> u32 {
+    let first = t.edges.len();
+    for n in ks {
+        t.edges.push(ContractionEdge {
+            code_point: n.cp,
+            next_first_edge: 0,
+            weight_start: 0,
+            next_edge_len: 0,
+            weight_len: 0,
+        });
+    }
+    for (i, n) in ks.iter().enumerate() {
+        if let Some(r) = &n.row {
+            t.edges[first + i].weight_start = t.weights.len() as u32;
+            t.edges[first + i].weight_len = r.len() as u16;
+            t.weights.extend(r);
+        }
+        if !n.kids.is_empty() {
+            let f = push_kids(t, &n.kids);
+            t.edges[first + i].next_first_edge = f;
+            t.edges[first + i].next_edge_len = n.kids.len() as u16;
+        }
+    }
+    first as u32
+}
+
+// Overlay entries for each code point that starts a tailored sequence: its own row, and the
+// contractions it starts (the root's, unless suppressed, with the tailored ones merged in)
+pub fn overlay_entries(
+    rows: &BTreeMap<Vec<u32>, Vec<u32>>,
+    root: &Root,
+    suppress: &[u32],
+    remap: &dyn Fn(u32) -> u32,
+) -> BTreeMap<u32, Ent> {
+    // Root contraction rows must follow a reordering too
+    fn fix(ns: &mut [Node], f: &dyn Fn(u32) -> u32) {
+        for n in ns {
+            if let Some(r) = &mut n.row {
+                for w in r.iter_mut() {
+                    *w = f(*w);
+                }
+            }
+            fix(&mut n.kids, f);
+        }
+    }
+
+    let mut changes: BTreeMap<u32, Ent> = BTreeMap::new();
+    let starters: BTreeSet<u32> = rows.keys().map(|s| s[0]).collect();
+    for &cp in &starters {
+        let own = rows.get(&vec![cp]).cloned().unwrap_or_else(|| {
+            root.weights(&[cp])
+                .unwrap()
+                .iter()
+                .map(|&x| remap(x))
+                .collect()
+        });
+        let mut kids = if suppress.contains(&cp) {
+            vec![]
+        } else {
+            root.kids(cp)
+        };
+        fix(&mut kids, remap);
+        for (seq, row) in rows.range(vec![cp]..).take_while(|(s, _)| s[0] == cp) {
+            if seq.len() > 1 {
+                insert(&mut kids, &seq[1..], row.clone());
+            }
+        }
+        let e = if kids.is_empty() {
+            Ent::Simple(own)
+        } else {
+            Ent::Contr(own, kids)
+        };
+        changes.insert(cp, e);
+    }
+    for &cp in suppress {
+        changes
+            .entry(cp)
+            .or_insert_with(|| Ent::Simple(root.weights(&[cp]).unwrap()));
+    }
+    changes
+}
+
+// Canonical decompositions from data/nfd.txt
+pub fn load_nfd() -> BTreeMap<u32, Vec<u32>> {
+    std::fs::read_to_string(NFD)
+        .unwrap()
+        .lines()
+        .map(|l| {
+            let (cp, d) = l.split_once(';').unwrap();
+            (u32::from_str_radix(cp, 16).unwrap(), parse_seq(d))
+        })
+        .collect()
+}
+
+fn ccc(c: u32) -> u8 {
+    unicode_canonical_combining_class::get_canonical_combining_class_u32(c) as u8
+}
+
+pub fn nfd(decomp: &BTreeMap<u32, Vec<u32>>, s: &[u32]) -> Vec<u32> {
+    let mut out: Vec<u32> = s
+        .iter()
+        .flat_map(|cp| decomp.get(cp).cloned().unwrap_or_else(|| vec![*cp]))
+        .collect();
+    // Canonical ordering: a stable sort of each run of non-starters by combining class
+    let mut i = 0;
+    while i < out.len() {
+        let j = (i..out.len())
+            .find(|&j| ccc(out[j]) == 0)
+            .unwrap_or(out.len());
+        out[i..j].sort_by_key(|&c| ccc(c));
+        i = j + 1;
+    }
+    out
+}
+
+// Whether feruca would take the string as it is, without normalizing it: the combining classes at
+// the boundaries between code points (as decomposed) never go down, apart from starters
+pub fn fcd(decomp: &BTreeMap<u32, Vec<u32>>, s: &[u32]) -> bool {
+    let mut trail = 0;
+    for cp in s {
+        if *cp == 0x0F81 {
+            return false;
+        }
+        let d = decomp
+            .get(cp)
+            .map_or(std::slice::from_ref(cp), Vec::as_slice);
+        let lead = ccc(d[0]);
+        if lead != 0 && lead < trail {
+            return false;
+        }
+        trail = ccc(*d.last().unwrap());
+    }
+    true
+}
+
+fn find<'n>(mut ns: &'n [Node], tail: &[u32]) -> Option<&'n [u32]> {
+    let mut row = None;
+    for &cp in tail {
+        let i = ns.binary_search_by_key(&cp, |n| n.cp).ok()?;
+        row = ns[i].row.as_deref();
+        ns = &ns[i].kids;
+    }
+    row
+}
+
+// The table as the collator sees it: the overlay's entries over the root's, with the tailoring's
+// prefix mappings and Han order. `ces` matches a string the way feruca's `CeaCursor` does (the
+// longest contiguous contraction, extended past one blocked-off combining mark, or with up to two
+// later marks pulled in after a single code point), so that a string can be compared with its NFD.
+pub struct Model<'a> {
+    pub root: &'a Root,
+    pub changes: BTreeMap<u32, Ent>,
+    pub prefixes: &'a [(u32, Vec<u32>, Vec<u32>)],
+    pub han: Option<(u32, &'a BTreeMap<u32, u32>)>,
+}
+
+impl Model<'_> {
+    fn entry(&self, cp: u32) -> (Vec<u32>, &[Node]) {
+        match self.changes.get(&cp) {
+            Some(Ent::Simple(r)) => (r.clone(), &[]),
+            Some(Ent::Contr(r, ks)) => (r.clone(), ks),
+            None => {
+                let rank = self
+                    .han
+                    .filter(|_| !self.root.code_points.contains(&cp))
+                    .and_then(|(lead, ranks)| Some((lead, *ranks.get(&cp)?)));
+                let row = match rank {
+                    Some((lead, r)) => vec![pack(false, lead, 0x20, 2), pack(false, r, 0, 0)],
+                    None => self.root.weights(&[cp]).unwrap(),
+                };
+                let kids = self.root.contractions.get(&cp).map_or(&[][..], |k| k);
+                (row, kids)
+            }
+        }
+    }
+
+    // Every contraction of the overlay's entries
+    pub fn contractions(&self) -> Vec<Vec<u32>> {
+        fn walk(ns: &[Node], seq: &mut Vec<u32>, out: &mut Vec<Vec<u32>>) {
+            for n in ns {
+                seq.push(n.cp);
+                if n.row.is_some() {
+                    out.push(seq.clone());
+                }
+                walk(&n.kids, seq, out);
+                seq.pop();
+            }
+        }
+        let mut out = vec![];
+        for (&cp, e) in &self.changes {
+            if let Ent::Contr(_, ks) = e {
+                walk(ks, &mut vec![cp], &mut out);
+            }
+        }
+        out
+    }
+
+    pub fn ces(&self, s: &[u32]) -> Vec<u32> {
+        let mut s = s.to_vec();
+        let mut out = vec![];
+        let mut i = 0;
+        while i < s.len() {
+            let cp = s[i];
+            let prefixed = self
+                .prefixes
+                .iter()
+                .filter(|p| p.0 == cp && s[..i].ends_with(&p.1))
+                .max_by_key(|p| p.1.len());
+            if let Some(p) = prefixed {
+                out.extend(&p.2);
+                i += 1;
+                continue;
+            }
+            let (own, kids) = self.entry(cp);
+            let max_len = 1 + depth(kids) as usize;
+            let mut n = max_len.min(s.len() - i);
+            loop {
+                if n <= 1 {
+                    match pulled(kids, &s[i..]) {
+                        Some((at, two, row)) => {
+                            out.extend(row);
+                            s.remove(i + at);
+                            if two {
+                                s.remove(i + at - 1);
+                            }
+                        }
+                        None => out.extend(&own),
+                    }
+                    i += 1;
+                    break;
+                }
+                if let Some(row) = find(kids, &s[i + 1..i + n]) {
+                    // A combining mark after the match may extend it, skipping over one between
+                    let extended = s
+                        .get(i + n + 1)
+                        .filter(|&&next| {
+                            n < max_len && ccc(s[i + n]) > 0 && ccc(next) > ccc(s[i + n])
+                        })
+                        .and_then(|&next| {
+                            let mut tail = s[i + 1..i + n].to_vec();
+                            tail.push(next);
+                            find(kids, &tail)
+                        });
+                    match extended {
+                        Some(r) => {
+                            out.extend(r);
+                            s.remove(i + n + 1);
+                        }
+                        None => out.extend(row),
+                    }
+                    i += n;
+                    break;
+                }
+                n -= 1;
+            }
+        }
+        out
+    }
+}
+
+// After a single code point, a contraction with one or two of the next three code points, if the
+// combining marks up to them are in increasing order of combining class
+fn pulled<'n>(kids: &'n [Node], s: &[u32]) -> Option<(usize, bool, &'n [u32])> {
+    let mut at = (s.len() - 1).min(3);
+    let mut two = at == 3;
+    while at > 1 {
+        let increasing = s[1..=at]
+            .iter()
+            .try_fold(0, |max, &c| (ccc(c) > max).then(|| ccc(c)))
+            .is_some();
+        if !increasing {
+            two = false;
+            at -= 1;
+            continue;
+        }
+        let tail = if two { &s[at - 1..=at] } else { &s[at..=at] };
+        if let Some(row) = find(kids, tail) {
+            return Some((at, two, row));
+        }
+        if two {
+            two = false;
+        } else {
+            at -= 1;
+        }
+    }
+    None
+}
+
+// Map DUCET 13 primaries (as used by the perl locale files) onto our CLDR root primaries
+pub fn primary_map(root: &Root, allkeys: &HashMap<Vec<u32>, Vec<Vec<u32>>>) -> BTreeMap<u32, u32> {
+    let mut votes: BTreeMap<u32, BTreeMap<u32, usize>> = BTreeMap::new();
+    for (seq, ces) in allkeys {
+        if seq.len() != 1 || ces.len() != 1 || ces[0][0] == 0 {
+            continue;
+        }
+        let Some(ours) = root.info.weights(seq) else {
+            continue;
+        };
+        if ours.len() != 1 || ours[0].primary == 0 {
+            continue;
+        }
+        *votes
+            .entry(ces[0][0])
+            .or_default()
+            .entry(ours[0].primary as u32)
+            .or_default() += 1;
+    }
+    votes
+        .into_iter()
+        .map(|(k, v)| (k, v.into_iter().max_by_key(|(_, n)| *n).unwrap().0))
+        .collect()
+}
+
+// Map DUCET 13 secondaries onto ours, from the combining marks (single CEs with a zero primary)
+pub fn secondary_map(
+    root: &Root,
+    allkeys: &HashMap<Vec<u32>, Vec<Vec<u32>>>,
+) -> BTreeMap<u32, u32> {
+    let mut votes: BTreeMap<u32, BTreeMap<u32, usize>> = BTreeMap::new();
+    for (seq, ces) in allkeys {
+        if seq.len() != 1 || ces.len() != 1 || ces[0][0] != 0 || ces[0][1] == 0 {
+            continue;
+        }
+        let Some(ours) = root.info.weights(seq) else {
+            continue;
+        };
+        if ours.len() != 1 || ours[0].primary != 0 {
+            continue;
+        }
+        *votes
+            .entry(ces[0][1])
+            .or_default()
+            .entry(ours[0].secondary as u32)
+            .or_default() += 1;
+    }
+    votes
+        .into_iter()
+        .map(|(k, v)| (k, v.into_iter().max_by_key(|(_, n)| *n).unwrap().0))
+        .collect()
+}
+
+pub struct Mapper<'a> {
+    pub map: BTreeMap<u32, u32>,
+    pub smap: BTreeMap<u32, u32>,
+    // Secondary-only CEs with a zero tertiary are the locale files' way of adding secondaries after
+    // all others; give them fresh values above the root's, and an ordinary tertiary (a zero one would
+    // make the CE ignorable when shifting)
+    pub extra: BTreeMap<u32, u32>,
+    pub lowest: BTreeSet<u32>,
+    pub root: &'a Root,
+}
+
+impl Mapper<'_> {
+    // Allocate new primaries (those the locale file introduces) after the nearest mapped one
+    pub fn add_new(&mut self, new: &BTreeSet<u32>) {
+        let mut extra: BTreeMap<u32, u32> = BTreeMap::new();
+        for &p in new {
+            if self.map.contains_key(&p) {
+                continue;
+            }
+            // Primaries below all of the root's go at the start, after U+FFFE (and are not
+            // variable, as the locale file has them)
+            let (pred13, pred) = self
+                .map
+                .range(..p)
+                .next_back()
+                .map_or((0, 1), |(&a, &b)| (a, b));
+            if pred13 == 0 {
+                self.lowest.insert(p);
+            }
+            let k = new
+                .range(pred13 + 1..=p)
+                .filter(|q| !self.map.contains_key(q))
+                .count() as u32;
+            let ours = pred + k;
+            let next_used = *self.root.used.range(pred + 1..).next().unwrap();
+            assert!(
+                ours < next_used,
+                "no room for {p:04X} after {pred13:04X} (ours {pred:04X}, next used {next_used:04X})"
+            );
+            extra.insert(p, ours);
+        }
+        self.map.extend(extra);
+    }
+
+    pub fn ce(&self, ce: &[u32]) -> u32 {
+        // The locale files use trailing primaries from FF00 up to split one primary into several
+        let p = if ce[0] == 0 || ce[0] >= 0xFF00 {
+            ce[0]
+        } else {
+            self.map[&ce[0]]
+        };
+        let variable = p != 0 && p <= self.root.max_variable && !self.lowest.contains(&ce[0]);
+        let s = match if ce[0] == 0 {
+            self.extra.get(&ce[1])
+        } else {
+            None
+        }
+        .or(self.smap.get(&ce[1]))
+        {
+            Some(&s) => s,
+            None if ce[1] < 0xD3 => ce[1],
+            None => panic!("unmapped secondary {:x}", ce[1]),
+        };
+        let t = if ce[0] == 0 && ce[2] == 0 && self.extra.contains_key(&ce[1]) {
+            2
+        } else {
+            ce[2]
+        };
+        pack(variable, p, s, t)
+    }
+}
+
+// A locale file's entries (each a sequence and its CEs, as lists of weights) and options
+type LocaleFile = (Vec<(Vec<u32>, Vec<Vec<u32>>)>, BTreeMap<String, String>);
+
+pub fn parse_locale(path: &str) -> LocaleFile {
+    let src = std::fs::read_to_string(path).unwrap();
+    let mut opts = BTreeMap::new();
+    let mut entries = Vec::new();
+    let mut in_entry = false;
+    for l in src.lines() {
+        if in_entry {
+            if l.starts_with("ENTRY") {
+                in_entry = false;
+                continue;
+            }
+            let (k, v) = l.split_once(';').unwrap();
+            let v = v.split('#').next().unwrap();
+            entries.push((parse_seq(k), parse_ces(v)));
+        } else if l.contains("entry =>") {
+            in_entry = true;
+        } else if let Some((k, v)) = l.split_once("=>") {
+            opts.insert(
+                k.trim().to_string(),
+                v.trim().trim_end_matches(',').to_string(),
+            );
+        }
+    }
+    (entries, opts)
+}
+
+#[derive(Serialize)]
+pub struct HanTable {
+    pub lead: u16,
+    pub page_index: Vec<u16>,
+    pub pages: Vec<u16>,
+}
+
+// Ranks from a Unicode::Collate::CJK module: 8000 up, in order, counting the index markers too
+pub fn parse_han(path: &str) -> BTreeMap<u32, u32> {
+    let src = std::fs::read_to_string(path).unwrap();
+    let data = src
+        .split("__DATA__")
+        .nth(1)
+        .unwrap()
+        .split("__END__")
+        .next()
+        .unwrap();
+    let mut ranks = BTreeMap::new();
+    let mut wt = 0x8000;
+    for c in data.split_whitespace() {
+        if !c.contains('-') {
+            ranks.insert(u32::from_str_radix(c, 16).unwrap(), wt);
+        }
+        wt += 1;
+    }
+    assert!(wt <= 0x10000);
+    ranks
+}
+
+pub fn han_table(ranks: &BTreeMap<u32, u32>, lead: u32) -> HanTable {
+    let mut page_index = vec![u16::MAX; 0x1100];
+    let mut pages: Vec<u16> = Vec::new();
+    for (&cp, &r) in ranks {
+        let block = (cp >> 8) as usize;
+        if page_index[block] == u16::MAX {
+            page_index[block] = (pages.len() / 256) as u16;
+            pages.resize(pages.len() + 256, 0);
+        }
+        pages[(page_index[block] as usize) * 256 + (cp & 0xFF) as usize] = r as u16;
+    }
+    HanTable {
+        lead: lead as u16,
+        page_index,
+        pages,
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let locale = &args[1];
+    let out = &args[2];
+    let mut suppress: Vec<u32> = vec![];
+    let mut reorders: Vec<(u32, u32, u32)> = vec![];
+    let mut han: Option<(String, String, u32)> = None;
+    let mut prefix_cps: Vec<u32> = vec![];
+    let mut kana = false;
+    let mut korean: Option<String> = None;
+    let mut emoji: Option<String> = None;
+    let mut i = 3;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--suppress" => {
+                suppress = args[i + 1]
+                    .split(',')
+                    .map(|x| u32::from_str_radix(x, 16).unwrap())
+                    .collect();
+                i += 2;
+            }
+            "--prefix" => {
+                prefix_cps = args[i + 1]
+                    .split(',')
+                    .map(|x| u32::from_str_radix(x, 16).unwrap())
+                    .collect();
+                i += 2;
+            }
+            "--korean" => {
+                korean = Some(args[i + 1].clone());
+                i += 2;
+            }
+            "--emoji" => {
+                emoji = Some(args[i + 1].clone());
+                i += 2;
+            }
+            "--kana" => {
+                kana = true;
+                i += 1;
+            }
+            "--han" => {
+                han = Some((
+                    args[i + 1].clone(),
+                    args[i + 2].clone(),
+                    u32::from_str_radix(&args[i + 3], 16).unwrap(),
+                ));
+                i += 4;
+            }
+            "--reorder" => {
+                let (range, to) = args[i + 1].split_once(':').unwrap();
+                let (lo, hi) = range.split_once('-').unwrap();
+                reorders.push((
+                    u32::from_str_radix(lo, 16).unwrap(),
+                    u32::from_str_radix(hi, 16).unwrap(),
+                    u32::from_str_radix(to, 16).unwrap(),
+                ));
+                i += 2;
+            }
+            _ => panic!("unknown option {}", args[i]),
+        }
+    }
+
+    let root = Root::load();
+    let allkeys = load_allkeys();
+    let (entries, opts) = if locale == "-" {
+        (vec![], BTreeMap::new())
+    } else {
+        parse_locale(locale)
+    };
+    eprintln!("options {opts:?}; {} entries", entries.len());
+
+    let smap = secondary_map(&root, &allkeys);
+    let max_s = root
+        .info
+        .code_points()
+        .flat_map(|cp| root.weights(&[cp]).unwrap().to_vec())
+        .map(|w| w & 0x1FF)
+        .max()
+        .unwrap();
+    let extra_s: BTreeSet<u32> = entries
+        .iter()
+        .flat_map(|(_, ces)| ces.iter())
+        .filter(|c| {
+            c[0] == 0 && c[1] != 0 && (c[2] == 0 || (c[1] >= 0xD3 && !smap.contains_key(&c[1])))
+        })
+        .map(|c| c[1])
+        .collect();
+    let extra: BTreeMap<u32, u32> = extra_s
+        .iter()
+        .enumerate()
+        .map(|(i, &s)| (s, max_s + 1 + i as u32))
+        .collect();
+    if !extra.is_empty() {
+        eprintln!("max secondary {max_s:X}; extra {extra:X?}");
+        assert!(max_s + extra.len() as u32 <= 0x1FF);
+    }
+    let mut mapper = Mapper {
+        map: primary_map(&root, &allkeys),
+        smap,
+        extra,
+        lowest: BTreeSet::new(),
+        root: &root,
+    };
+    let is_rank = |p: u32| han.is_some() && (0x8000..0xFB00).contains(&p);
+    let new: BTreeSet<u32> = entries
+        .iter()
+        .flat_map(|(_, ces)| ces.iter().map(|c| c[0]))
+        .filter(|&p| p != 0 && p < 0xFF00 && !is_rank(p))
+        .collect();
+    mapper.add_new(&new);
+
+    let remap = |w: u32| -> u32 {
+        let p = pr(w);
+        for &(lo, hi, to) in &reorders {
+            if (lo..=hi).contains(&p) {
+                return ((p - lo + to) << 16) | (w & 0xFFFF);
+            }
+        }
+        w
+    };
+
+    // Tailored rows, keyed by sequence
+    let mut rows: BTreeMap<Vec<u32>, Vec<u32>> = BTreeMap::new();
+    for (seq, ces) in &entries {
+        let mut row = Vec::new();
+        for c in ces {
+            if is_rank(c[0]) {
+                let lead = han.as_ref().unwrap().2;
+                row.push(mapper.ce(&[0, c[1], c[2]]) | (lead << 16));
+                row.push(pack(false, c[0], 0, 0));
+            } else {
+                let mut w = remap(mapper.ce(c));
+                // A trailing primary that splits a variable weight is itself variable
+                if c[0] >= 0xFF00 && row.last().is_some_and(|&x: &u32| x & 0x8000 != 0) {
+                    w |= 0x8000;
+                }
+                // (not the tails of the prolonged sound mark and the iteration marks, the same after either)
+                if kana && c.len() == 4 && c[3] == 2 && c[2] != 7 && c[2] != 0x10 {
+                    w |= 0x20 << 9;
+                }
+                row.push(w);
+            }
+        }
+        rows.insert(seq.clone(), row);
+    }
+
+    // Hanja by reading: each follows its Hangul syllable at the secondary level, in the module's order
+    if let Some(module) = &korean {
+        let src = std::fs::read_to_string(module).unwrap();
+        let data = src
+            .split("__DATA__")
+            .nth(1)
+            .unwrap()
+            .split("__END__")
+            .next()
+            .unwrap();
+        let mut jamo: Vec<u32> = vec![];
+        let mut n = 0;
+        let mut hanja: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+        for l in data.lines() {
+            let c: Vec<&str> = l.split_whitespace().collect();
+            if c.len() == 1 && c[0].contains(':') {
+                jamo = c[0]
+                    .split_once(':')
+                    .unwrap()
+                    .1
+                    .split('-')
+                    .map(|x| u32::from_str_radix(x, 16).unwrap())
+                    .collect();
+                n = 0;
+                continue;
+            }
+            for x in c {
+                let mut row: Vec<u32> = jamo
+                    .iter()
+                    .flat_map(|&j| root.weights(&[j]).unwrap())
+                    .collect();
+                assert_eq!(row.len(), jamo.len());
+                row[0] = (row[0] & !0x1FF) | (max_s + 1 + n);
+                assert!(max_s + 1 + n <= 0x1FF);
+                n += 1;
+                hanja.insert(u32::from_str_radix(x, 16).unwrap(), row);
+            }
+        }
+        eprintln!("{} hanja", hanja.len());
+        for (cp, row) in hanja {
+            rows.insert(vec![cp], row.iter().map(|&x| remap(x)).collect());
+        }
+    }
+
+    // Emoji order: each sequence (less its variation selectors) gets a fresh primary after the last
+    // regular one; a skin-tone variant takes its base's primary followed by the modifiers' weights
+    if let Some(file) = &emoji {
+        let mut next = root
+            .used
+            .iter()
+            .copied()
+            .filter(|&p| p < 0x8000)
+            .max()
+            .unwrap()
+            + 1;
+        let first = next;
+        let is_mod = |c: u32| (0x1F3FB..=0x1F3FF).contains(&c);
+        let single = |c: u32, rows: &BTreeMap<Vec<u32>, Vec<u32>>| {
+            rows.get(&vec![c])
+                .cloned()
+                .unwrap_or_else(|| root.weights(&[c]).unwrap())
+        };
+        let mut n = 0;
+        for l in std::fs::read_to_string(file).unwrap().lines() {
+            let seq: Vec<u32> = parse_seq(l).into_iter().filter(|&c| c != 0xFE0F).collect();
+            let base: Vec<u32> = seq.iter().copied().filter(|&c| !is_mod(c)).collect();
+            let row = if base.len() < seq.len() && rows.contains_key(&base) {
+                let mut r = vec![rows[&base][0]];
+                r.extend(
+                    seq.iter()
+                        .filter(|&&c| is_mod(c))
+                        .flat_map(|&c| root.weights(&[c]).unwrap()),
+                );
+                r
+            } else {
+                next += 1;
+                vec![pack(false, next - 1, 0x20, 2)]
+            };
+            let natural: Vec<u32> = seq.iter().flat_map(|&c| single(c, &rows)).collect();
+            if row != natural {
+                // The fully qualified form too, for input that keeps its variation selectors
+                let full = parse_seq(l);
+                if seq.len() > 1 && full != seq {
+                    rows.insert(full, row.clone());
+                }
+                rows.insert(seq, row);
+                n += 1;
+            }
+        }
+        eprintln!("emoji primaries {first:X}..{:X}, {n} rows", next - 1);
+        assert!(next <= 0x8000);
+    }
+
+    // Prefix mappings: contractions ending in one of the given code points become mappings for that
+    // code point after the rest of the sequence
+    let mut prefixes: Vec<(u32, Vec<u32>, Vec<u32>)> = vec![];
+    if !prefix_cps.is_empty() {
+        let keys: Vec<Vec<u32>> = rows
+            .keys()
+            .filter(|s| s.len() > 1 && prefix_cps.contains(s.last().unwrap()))
+            .cloned()
+            .collect();
+        for seq in keys {
+            let row = rows.remove(&seq).unwrap();
+            let pre = &seq[..seq.len() - 1];
+            let pre_row = match rows.get(pre) {
+                Some(r) => r.clone(),
+                None => pre
+                    .iter()
+                    .flat_map(|&cp| {
+                        rows.get(&vec![cp])
+                            .cloned()
+                            .unwrap_or_else(|| root.weights(&[cp]).unwrap())
+                    })
+                    .map(remap)
+                    .collect(),
+            };
+            assert!(
+                row.starts_with(&pre_row),
+                "{seq:X?}: {row:X?} vs {pre_row:X?}"
+            );
+            prefixes.push((
+                *seq.last().unwrap(),
+                pre.to_vec(),
+                row[pre_row.len()..].to_vec(),
+            ));
+        }
+        // Canonical closure: the voiced iteration marks decompose to the plain ones plus U+3099
+        let mut closure = vec![];
+        for l in std::fs::read_to_string(NFD).unwrap().lines() {
+            let (cp, d) = l.split_once(';').unwrap();
+            let cp = u32::from_str_radix(cp, 16).unwrap();
+            let d = parse_seq(d);
+            for (target, pre, tail) in &prefixes {
+                if d[0] == *target && !prefixes.iter().any(|p| p.0 == cp && &p.1 == pre) {
+                    let mut row = tail.clone();
+                    for &m in &d[1..] {
+                        row.extend(root.weights(&[m]).unwrap().iter().map(|&x| remap(x)));
+                    }
+                    closure.push((cp, pre.clone(), row));
+                }
+            }
+        }
+        eprintln!("prefix closure adds {}", closure.len());
+        prefixes.extend(closure);
+        eprintln!("{} prefix mappings", prefixes.len());
+        for &cp in &prefix_cps {
+            if !rows.contains_key(&vec![cp]) && prefixes.iter().any(|p| p.0 == cp) {
+                rows.insert(
+                    vec![cp],
+                    root.weights(&[cp])
+                        .unwrap()
+                        .iter()
+                        .map(|&x| remap(x))
+                        .collect(),
+                );
+            }
+        }
+    }
+
+    // Han order: a rank table, plus explicit rows for ranked code points that the root lists
+    let ranks = han.as_ref().map(|(module, _, _)| parse_han(module));
+    if let Some(((_, han_out, lead), ranks)) = han.as_ref().zip(ranks.as_ref()) {
+        let mut n = 0;
+        for (&cp, &r) in ranks {
+            if root.code_points.contains(&cp) && !rows.contains_key(&vec![cp]) {
+                rows.insert(
+                    vec![cp],
+                    vec![pack(false, *lead, 0x20, 2), pack(false, r, 0, 0)],
+                );
+                n += 1;
+            }
+        }
+        let t = han_table(ranks, *lead);
+        eprintln!(
+            "han {} ranks, {} explicit, {} pages",
+            ranks.len(),
+            n,
+            t.pages.len() / 256
+        );
+        std::fs::write(han_out, postcard::to_allocvec(&t).unwrap()).unwrap();
+    }
+
+    // Everything so far is the tailoring's own; the closure below may replace the rest
+    let tailored: BTreeSet<Vec<u32>> = rows.keys().cloned().collect();
+
+    // Reordered scripts: every root mapping touching the moved primaries
+    if !reorders.is_empty() {
+        let moved = |w: u32| {
+            reorders
+                .iter()
+                .any(|&(lo, hi, _)| (lo..=hi).contains(&pr(w)))
+        };
+        for cp in root.info.code_points() {
+            let w = root.weights(&[cp]).unwrap();
+            if w.iter().any(|&x| moved(x)) && !rows.contains_key(&vec![cp]) {
+                rows.insert(vec![cp], w.iter().map(|&x| remap(x)).collect());
+            }
+        }
+        for seq in root.info.contractions() {
+            let w = root.weights(&seq).unwrap();
+            if w.iter().any(|&x| moved(x))
+                && !rows.contains_key(&seq)
+                && !suppress.contains(&seq[0])
+            {
+                rows.insert(seq, w.iter().map(|&x| remap(x)).collect());
+            }
+        }
+    }
+
+    // Canonical closure. feruca decomposes input only when it isn't in FCD form already, so each
+    // precomposed character must get the weights of its NFD, wherever a tailored mapping could make
+    // them differ: on its own (e.g., "ñ" in Vietnamese, which tailors the tilde), before a combining
+    // mark that continues a contraction (e.g., "į" and a grave accent in pinyin, which has a
+    // contraction for "ì"), and after the start of a contraction whose next code point begins its
+    // decomposition (e.g., "Aå" in Danish, which has a contraction for "aa"). Additions can make
+    // room for others (or make them unnecessary), so repeat until there are none.
+    let decomp = load_nfd();
+    let root_marks: BTreeSet<u32> = root
+        .info
+        .contractions()
+        .flat_map(|seq| seq[1..].to_vec())
+        .filter(|&c| ccc(c) != 0)
+        .collect();
+    loop {
+        let model = Model {
+            root: &root,
+            changes: overlay_entries(&rows, &root, &suppress, &remap),
+            prefixes: &prefixes,
+            han: han.as_ref().zip(ranks.as_ref()).map(|(h, r)| (h.2, r)),
+        };
+        let contractions = model.contractions();
+        let mut marks: BTreeSet<u32> = contractions
+            .iter()
+            .flat_map(|seq| seq[1..].to_vec())
+            .filter(|&c| ccc(c) != 0)
+            .chain(root_marks.iter().copied())
+            .collect();
+        // (and the precomposed marks that decompose to one of them, e.g., U+0344)
+        let composite: Vec<u32> = decomp
+            .iter()
+            .filter(|(_, d)| marks.contains(&d[0]))
+            .map(|(&cp, _)| cp)
+            .collect();
+        marks.extend(composite);
+        let mut candidates: BTreeSet<Vec<u32>> = BTreeSet::new();
+        for &cp in decomp.keys() {
+            candidates.insert(vec![cp]);
+            candidates.extend(marks.iter().map(|&m| vec![cp, m]));
+        }
+        for seq in &contractions {
+            for k in 1..seq.len() {
+                for (&cp, d) in &decomp {
+                    if d[0] == seq[k] {
+                        candidates.insert([&seq[..k], &[cp]].concat());
+                    }
+                }
+            }
+        }
+        let mut closure = vec![];
+        for seq in candidates {
+            if tailored.contains(&seq) || !fcd(&decomp, &seq) {
+                continue;
+            }
+            // (Completely ignorable elements are left out of sort keys, so they don't count)
+            let ces =
+                |s: &[u32]| -> Vec<u32> { model.ces(s).into_iter().filter(|&w| w != 0).collect() };
+            let row = ces(&nfd(&decomp, &seq));
+            if ces(&seq) != row {
+                eprintln!("closure {seq:04X?}");
+                closure.push((seq, row));
+            }
+        }
+        if closure.is_empty() {
+            break;
+        }
+        // Single code points first: with their own rows fixed, many longer sequences need nothing
+        if closure.iter().any(|(seq, _)| seq.len() == 1) {
+            closure.retain(|(seq, _)| seq.len() == 1);
+        }
+        eprintln!("closure adds {}", closure.len());
+        rows.extend(closure);
+    }
+
+    let changes = overlay_entries(&rows, &root, &suppress, &remap);
+    let t = build_overlay(&changes, &prefixes);
+    eprintln!(
+        "pages {} entries {} meta {} edges {} weights {}",
+        t.page_index.iter().filter(|&&p| p != u16::MAX).count(),
+        t.entries.len(),
+        t.contraction_meta.len(),
+        t.edges.len(),
+        t.weights.len()
+    );
+    std::fs::write(out, postcard::to_allocvec(&t).unwrap()).unwrap();
+}